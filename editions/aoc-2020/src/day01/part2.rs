@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::search_if_eq, INPUT};
+use super::common::search_if_eq;
 
-pub fn run() -> usize {
-    search_if_eq(INPUT, 3, 2020).into_iter().product()
+pub fn run(input: &str) -> usize {
+    search_if_eq(input, 3, 2020).into_iter().product()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day01::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 259_521_570);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
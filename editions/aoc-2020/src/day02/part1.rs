@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::validate_multiple_passwords_with_count, INPUT};
+use super::common::validate_multiple_passwords_with_count;
 
-pub fn run() -> usize {
-    validate_multiple_passwords_with_count(INPUT)
+pub fn run(input: &str) -> usize {
+    validate_multiple_passwords_with_count(input)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day02::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 418);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::validate_multiple_passwords_with_position, INPUT};
+use super::common::validate_multiple_passwords_with_position;
 
-pub fn run() -> usize {
-    validate_multiple_passwords_with_position(INPUT)
+pub fn run(input: &str) -> usize {
+    validate_multiple_passwords_with_position(input)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day02::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 616);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
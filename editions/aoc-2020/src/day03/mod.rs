@@ -81,8 +81,30 @@
 //!
 //! <em>What do you get if you multiply together the number of trees encountered on each of the listed slopes?</em>
 
+use aoc_sx::core::{exercise::Answer, solution::Solution};
+
 pub mod common;
 pub mod part1;
 pub mod part2;
 
-const INPUT: &str = include_str!("./input.txt");
+/// Day solution.
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        part1::run(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<impl Into<Answer>> {
+        Some(part2::run(input))
+    }
+}
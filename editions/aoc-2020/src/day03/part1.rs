@@ -1,16 +1,26 @@
 //! Part 1
 
-use super::{common::TobogganMap, INPUT};
+use super::common::TobogganMap;
 
-pub fn run() -> usize {
+pub fn run(input: &str) -> usize {
     let (x, y) = (3, 1);
-    TobogganMap::from_input(INPUT).follow_slope(x, y)
+    TobogganMap::from_input(input).follow_slope(x, y)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day03::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 299);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
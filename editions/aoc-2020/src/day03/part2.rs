@@ -1,9 +1,9 @@
 //! Part 2
 
-use super::{common::TobogganMap, INPUT};
+use super::common::TobogganMap;
 
-pub fn run() -> usize {
-    let map = TobogganMap::from_input(INPUT);
+pub fn run(input: &str) -> usize {
+    let map = TobogganMap::from_input(input);
 
     [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
         .iter()
@@ -13,8 +13,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day03::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 3_621_285_278);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
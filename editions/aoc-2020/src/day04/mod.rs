@@ -132,8 +132,30 @@
 //!
 //! Count the number of <em>valid</em> passports - those that have all required fields <em>and valid values</em>. Continue to treat <code>cid</code> as optional. <em>In your batch file, how many passports are valid?</em>
 
+use aoc_sx::core::{exercise::Answer, solution::Solution};
+
 pub mod common;
 pub mod part1;
 pub mod part2;
 
-const INPUT: &str = include_str!("./input.txt");
+/// Day solution.
+pub struct Day;
+
+impl Solution for Day {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 4;
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+        part1::run(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Option<impl Into<Answer>> {
+        Some(part2::run(input))
+    }
+}
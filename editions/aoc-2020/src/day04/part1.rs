@@ -1,9 +1,9 @@
 //! Part 1
 
-use super::{common::PassportValidator, INPUT};
+use super::common::PassportValidator;
 
-pub fn run() -> usize {
-    PassportValidator::parse_entries(INPUT)
+pub fn run(input: &str) -> usize {
+    PassportValidator::parse_entries(input)
         .iter()
         .filter(|x| x.is_valid())
         .count()
@@ -11,8 +11,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day04::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 213);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
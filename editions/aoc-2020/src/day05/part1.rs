@@ -1,9 +1,9 @@
 //! Part 1
 
-use super::{common::BoardingPass, INPUT};
+use super::common::BoardingPass;
 
-pub fn run() -> usize {
-    BoardingPass::from_entries(INPUT)
+pub fn run(input: &str) -> usize {
+    BoardingPass::from_entries(input)
         .iter()
         .map(BoardingPass::get_seat_id)
         .max()
@@ -12,8 +12,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day05::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 818);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,9 +1,9 @@
 //! Part 2
 
-use super::{common::BoardingPass, INPUT};
+use super::common::BoardingPass;
 
-pub fn run() -> usize {
-    let passes = BoardingPass::from_entries(INPUT);
+pub fn run(input: &str) -> usize {
+    let passes = BoardingPass::from_entries(input);
 
     let mut seats: Vec<usize> = passes.iter().map(BoardingPass::get_seat_id).collect();
     seats.sort_unstable();
@@ -22,8 +22,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day05::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 559);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,9 +1,9 @@
 //! Part 1
 
-use super::{common::count_unique_questions_for_anyone, INPUT};
+use super::common::count_unique_questions_for_anyone;
 
-pub fn run() -> usize {
-    INPUT
+pub fn run(input: &str) -> usize {
+    input
         .split("\n\n")
         .map(count_unique_questions_for_anyone)
         .sum()
@@ -11,8 +11,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day06::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 6714);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
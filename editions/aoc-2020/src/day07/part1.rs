@@ -1,20 +1,27 @@
 //! Part 1
 
-use super::{
-    common::{BagColor, BagSystem, INPUT_COLOR_NAME},
-    INPUT,
-};
+use super::common::{BagColor, BagSystem, INPUT_COLOR_NAME};
 
-pub fn run() -> usize {
-    let system = BagSystem::new_from_rules(INPUT);
+pub fn run(input: &str) -> usize {
+    let system = BagSystem::new_from_rules(input);
     let color: BagColor = INPUT_COLOR_NAME.into();
     system.find_container_colors_for_color(&color).len()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day07::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 378);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
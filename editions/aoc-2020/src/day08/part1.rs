@@ -1,12 +1,9 @@
 //! Part 1
 
-use super::{
-    common::{Interpreter, StepOutput},
-    INPUT,
-};
+use super::common::{Interpreter, StepOutput};
 
-pub fn run() -> usize {
-    if let StepOutput::LoopFound(e) = Interpreter::new_from_code(INPUT).run() {
+pub fn run(input: &str) -> usize {
+    if let StepOutput::LoopFound(e) = Interpreter::new_from_code(input).run() {
         e as usize
     } else {
         panic!("Code should loop");
@@ -15,8 +12,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day08::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1930);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
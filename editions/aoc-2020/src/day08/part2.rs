@@ -1,12 +1,9 @@
 //! Part 2
 
-use super::{
-    common::{Interpreter, StepOutput},
-    INPUT,
-};
+use super::common::{Interpreter, StepOutput};
 
-pub fn run() -> usize {
-    if let StepOutput::Finished(e) = Interpreter::new_from_code(INPUT).run_repair_mode() {
+pub fn run(input: &str) -> usize {
+    if let StepOutput::Finished(e) = Interpreter::new_from_code(input).run_repair_mode() {
         e as usize
     } else {
         panic!("Code should finish");
@@ -15,8 +12,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day08::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1688);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::XmasScanner, INPUT};
+use super::common::XmasScanner;
 
-pub fn run() -> usize {
-    XmasScanner::parse_and_find_error(INPUT, 25).unwrap()
+pub fn run(input: &str) -> usize {
+    XmasScanner::parse_and_find_error(input, 25).unwrap()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day09::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 556_543_474);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,18 +1,28 @@
 //! Part 2
 
-use super::{common::XmasScanner, INPUT};
+use super::common::XmasScanner;
 
-pub fn run() -> usize {
-    let target = super::part1::run();
-    XmasScanner::find_weakness(INPUT, target)
+pub fn run(input: &str) -> usize {
+    let target = super::part1::run(input);
+    XmasScanner::find_weakness(input, target)
         .map(|x| x.get_sum())
         .unwrap()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day09::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 76_096_372);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
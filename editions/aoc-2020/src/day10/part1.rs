@@ -1,10 +1,10 @@
 //! Part 1
 
-use super::{common::JoltAnalyzer, INPUT};
+use super::common::JoltAnalyzer;
 
-pub fn run() -> usize {
+pub fn run(input: &str) -> usize {
     let (diff1, diff3) = JoltAnalyzer::get_1x3_jolt_differences(
-        &JoltAnalyzer::from_input(INPUT).determine_jolt_chain(),
+        &JoltAnalyzer::from_input(input).determine_jolt_chain(),
     );
 
     diff1 * diff3
@@ -12,8 +12,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day10::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1820);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::JoltAnalyzer, INPUT};
+use super::common::JoltAnalyzer;
 
-pub fn run() -> usize {
-    JoltAnalyzer::from_input(INPUT).count_adapter_permutations()
+pub fn run(input: &str) -> usize {
+    JoltAnalyzer::from_input(input).count_adapter_permutations()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day10::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 3_454_189_699_072);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 1
 
-use super::{common::SeatLayout, INPUT};
+use super::common::SeatLayout;
 
-pub fn run() -> usize {
-    SeatLayout::from_input(INPUT)
+pub fn run(input: &str) -> usize {
+    SeatLayout::from_input(input)
         .run_until_stable()
         .occupied_seats
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day11::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 2344);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 2
 
-use super::{common::SeatLayout, INPUT};
+use super::common::SeatLayout;
 
-pub fn run() -> usize {
-    SeatLayout::from_input(INPUT)
+pub fn run(input: &str) -> usize {
+    SeatLayout::from_input(input)
         .run_with_visibility_until_stable()
         .occupied_seats
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day11::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 2076);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,18 +1,25 @@
 //! Part 2
 
-use super::{
-    common::{compute_manhattan_distance, Ship},
-    INPUT,
-};
+use super::common::{compute_manhattan_distance, Ship};
 
-pub fn run() -> usize {
-    compute_manhattan_distance(Ship::new().parse_and_execute_input_commands_waypoint(INPUT))
+pub fn run(input: &str) -> usize {
+    compute_manhattan_distance(Ship::new().parse_and_execute_input_commands_waypoint(input))
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day12::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 71586);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
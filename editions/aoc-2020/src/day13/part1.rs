@@ -1,12 +1,9 @@
 //! Part 1
 
-use super::{
-    common::{extract_schedules, Scheduler},
-    INPUT,
-};
+use super::common::{extract_schedules, Scheduler};
 
-pub fn run() -> usize {
-    let (target, schedule) = extract_schedules(INPUT);
+pub fn run(input: &str) -> usize {
+    let (target, schedule) = extract_schedules(input);
     let scheduler = Scheduler::from_input(schedule);
     let (wait_time, bus_id) = scheduler.scan_buses_for_target_time(target);
 
@@ -15,8 +12,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day13::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 136);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,19 +1,26 @@
 //! Part 2
 
-use super::{
-    common::{extract_schedules, Scheduler},
-    INPUT,
-};
+use super::common::{extract_schedules, Scheduler};
 
-pub fn run() -> usize {
-    let (_, schedule) = extract_schedules(INPUT);
+pub fn run(input: &str) -> usize {
+    let (_, schedule) = extract_schedules(input);
     Scheduler::from_input(schedule).compute_successive_departures_time()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day13::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 305_068_317_272_992);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
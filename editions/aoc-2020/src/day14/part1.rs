@@ -1,10 +1,10 @@
 //! Part 1
 
-use super::{common::BitmaskMemory, INPUT};
+use super::common::BitmaskMemory;
 
-pub fn run() -> usize {
+pub fn run(input: &str) -> usize {
     let mut mem = BitmaskMemory::new();
-    for l in INPUT.lines() {
+    for l in input.lines() {
         mem.parse_line(l, false);
     }
 
@@ -13,8 +13,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day14::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 8_471_403_462_063);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
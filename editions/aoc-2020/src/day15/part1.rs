@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::MemoryGame, INPUT};
+use super::common::MemoryGame;
 
-pub fn run() -> usize {
-    MemoryGame::from_str_input(INPUT).run_steps(2020)
+pub fn run(input: &str) -> usize {
+    MemoryGame::from_str_input(input).run_steps(2020)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day15::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 206);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::MemoryGame, INPUT};
+use super::common::MemoryGame;
 
-pub fn run() -> usize {
-    MemoryGame::from_str_input(INPUT).run_steps(30_000_000)
+pub fn run(input: &str) -> usize {
+    MemoryGame::from_str_input(input).run_steps(30_000_000)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day15::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 955);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,9 +1,9 @@
 //! Part 1
 
-use super::{common::InputParser, INPUT};
+use super::common::InputParser;
 
-pub fn run() -> usize {
-    InputParser::from(INPUT)
+pub fn run(input: &str) -> usize {
+    InputParser::from(input)
         .validate_nearby_tickets()
         .iter()
         .sum()
@@ -11,8 +11,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day16::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 22_000);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,9 +1,9 @@
 //! Part 2
 
-use super::{common::InputParser, INPUT};
+use super::common::InputParser;
 
-pub fn run() -> usize {
-    let input = InputParser::from(INPUT);
+pub fn run(input: &str) -> usize {
+    let input = InputParser::from(input);
 
     input
         .map_ticket_fields()
@@ -20,8 +20,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day16::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 410_460_648_673);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 1
 
-use super::{common::Conway3D, INPUT};
+use super::common::Conway3D;
 
-pub fn run() -> usize {
-    let mut game = Conway3D::from(INPUT);
+pub fn run(input: &str) -> usize {
+    let mut game = Conway3D::from(input);
     game.run_steps(6);
     game.count_active_cells()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day17::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 269);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 2
 
-use super::{common::Conway4D, INPUT};
+use super::common::Conway4D;
 
-pub fn run() -> usize {
-    let mut game = Conway4D::from(INPUT);
+pub fn run(input: &str) -> usize {
+    let mut game = Conway4D::from(input);
     game.run_steps(6);
     game.count_active_cells()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day17::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1380);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,9 +1,9 @@
 //! Part 1
 
-use super::{common::ExpressionParser, INPUT};
+use super::common::ExpressionParser;
 
-pub fn run() -> usize {
-    INPUT
+pub fn run(input: &str) -> usize {
+    input
         .trim()
         .lines()
         .map(|l| {
@@ -15,8 +15,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day18::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 45_283_905_029_161);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -5,18 +5,6 @@ use std::collections::HashMap;
 use aoc_sx::regex::Regex;
 use thiserror::Error;
 
-const INPUT_VALUES: &str = include_str!("input.txt");
-
-/// Part one answer.
-pub fn run_ex1() -> usize {
-    RuleSystem::from_rules_and_values(INPUT_VALUES).len()
-}
-
-/// Part two answer.
-pub fn run_ex2() -> usize {
-    RuleSystem::from_rules_and_values_alternative(INPUT_VALUES).len()
-}
-
 /// Day error.
 #[derive(Debug, Error)]
 pub enum DayError {
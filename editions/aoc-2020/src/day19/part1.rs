@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::RuleSystem, INPUT};
+use super::common::RuleSystem;
 
-pub fn run() -> usize {
-    RuleSystem::from_rules_and_values(INPUT).len()
+pub fn run(input: &str) -> usize {
+    RuleSystem::from_rules_and_values(input).len()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day19::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 118);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
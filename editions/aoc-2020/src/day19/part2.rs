@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::RuleSystem, INPUT};
+use super::common::RuleSystem;
 
-pub fn run() -> usize {
-    RuleSystem::from_rules_and_values_alternative(INPUT).len()
+pub fn run(input: &str) -> usize {
+    RuleSystem::from_rules_and_values_alternative(input).len()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day19::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 246);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,19 +1,26 @@
 //! Part 1
 
-use super::{
-    common::{TileMatcher, TileParser},
-    INPUT,
-};
+use super::common::{TileMatcher, TileParser};
 
-pub fn run() -> usize {
-    let tiles = TileParser::parse_multiple_from_input(INPUT);
+pub fn run(input: &str) -> usize {
+    let tiles = TileParser::parse_multiple_from_input(input);
     TileMatcher::find_puzzle_corners(&tiles).iter().product()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day20::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 140_656_720_229_539);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,12 +1,9 @@
 //! Part 2
 
-use super::{
-    common::{TileMatcher, TileParser},
-    INPUT,
-};
+use super::common::{TileMatcher, TileParser};
 
-pub fn run() -> usize {
-    let tiles = TileParser::parse_multiple_from_input(INPUT);
+pub fn run(input: &str) -> usize {
+    let tiles = TileParser::parse_multiple_from_input(input);
     let puzzle = TileMatcher::build_puzzle(&tiles);
     let puzzle = TileMatcher::find_and_replace_sea_monsters(&puzzle).unwrap();
 
@@ -19,8 +16,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day20::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1885);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,15 +1,12 @@
 //! Part 1
 
-use super::{
-    common::{
-        count_ingredients_for_allergens, extract_ingredients_without_allergens, parse_dishes,
-        resolve_allergen_map,
-    },
-    INPUT,
+use super::common::{
+    count_ingredients_for_allergens, extract_ingredients_without_allergens, parse_dishes,
+    resolve_allergen_map,
 };
 
-pub fn run() -> usize {
-    let dishes = parse_dishes(INPUT);
+pub fn run(input: &str) -> usize {
+    let dishes = parse_dishes(input);
     let map = count_ingredients_for_allergens(&dishes);
     let out = resolve_allergen_map(map);
     extract_ingredients_without_allergens(&dishes, out).len()
@@ -17,8 +14,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day21::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 2389);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,15 +1,12 @@
 //! Part 2
 
-use super::{
-    common::{
-        count_ingredients_for_allergens, get_canonical_dangerous_list_as_string, parse_dishes,
-        resolve_allergen_map,
-    },
-    INPUT,
+use super::common::{
+    count_ingredients_for_allergens, get_canonical_dangerous_list_as_string, parse_dishes,
+    resolve_allergen_map,
 };
 
-pub fn run() -> String {
-    let dishes = parse_dishes(INPUT);
+pub fn run(input: &str) -> String {
+    let dishes = parse_dishes(input);
     let map = count_ingredients_for_allergens(&dishes);
     let out = resolve_allergen_map(map);
     get_canonical_dangerous_list_as_string(&out)
@@ -17,11 +14,18 @@ pub fn run() -> String {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day21::Day;
+
     #[test]
     fn run() {
-        assert_eq!(
-            super::run(),
-            "fsr,skrxt,lqbcg,mgbv,dvjrrkv,ndnlm,xcljh,zbhp"
-        );
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
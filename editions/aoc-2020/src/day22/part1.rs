@@ -1,12 +1,9 @@
 //! Part 1
 
-use super::{
-    common::{calculate_score, parse_decks, run_game, Player},
-    INPUT,
-};
+use super::common::{calculate_score, parse_decks, run_game, Player};
 
-pub fn run() -> usize {
-    let ((_, mut deck1), (_, mut deck2)) = parse_decks(INPUT);
+pub fn run(input: &str) -> usize {
+    let ((_, mut deck1), (_, mut deck2)) = parse_decks(input);
     match run_game(&mut deck1, &mut deck2) {
         Player(1) => calculate_score(&deck1),
         Player(2) => calculate_score(&deck2),
@@ -16,8 +13,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day22::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 31754)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,12 +1,9 @@
 //! Part 2
 
-use super::{
-    common::{calculate_score, parse_decks, run_recursive_game, GameMemory, Player},
-    INPUT,
-};
+use super::common::{calculate_score, parse_decks, run_recursive_game, GameMemory, Player};
 
-pub fn run() -> usize {
-    let ((_, mut deck1), (_, mut deck2)) = parse_decks(INPUT);
+pub fn run(input: &str) -> usize {
+    let ((_, mut deck1), (_, mut deck2)) = parse_decks(input);
     let mut memory = GameMemory::default();
     match run_recursive_game(&mut deck1, &mut deck2, &mut memory, 1) {
         Player(1) => calculate_score(&deck1),
@@ -17,8 +14,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day22::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 35436)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,20 +1,27 @@
 //! Part 1
 
-use super::{
-    common::{parse_cups, run_steps},
-    INPUT,
-};
+use super::common::{parse_cups, run_steps};
 
-pub fn run() -> String {
-    let mut cups = parse_cups(INPUT);
+pub fn run(input: &str) -> String {
+    let mut cups = parse_cups(input);
     run_steps(&mut cups, 100);
     cups.to_string_from_one()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day23::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), "27865934")
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
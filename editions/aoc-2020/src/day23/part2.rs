@@ -1,12 +1,9 @@
 //! Part 2
 
-use super::{
-    common::{prepare_million_cups, run_steps},
-    INPUT,
-};
+use super::common::{prepare_million_cups, run_steps};
 
-pub fn run() -> usize {
-    let mut cups = prepare_million_cups(INPUT);
+pub fn run(input: &str) -> usize {
+    let mut cups = prepare_million_cups(input);
     run_steps(&mut cups, 10_000_000);
 
     let a = cups.next(1);
@@ -16,8 +13,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day23::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 170_836_011_000)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,21 +1,28 @@
 //! Part 2
 
-use super::{
-    common::{parse_paths, HexGrid},
-    INPUT,
-};
+use super::common::{parse_paths, HexGrid};
 
-pub fn run() -> usize {
+pub fn run(input: &str) -> usize {
     let mut grid = HexGrid::default();
-    grid.follow_paths(parse_paths(INPUT));
+    grid.follow_paths(parse_paths(input));
     grid.run_steps(100);
     grid.count_black_tiles()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day24::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 3672);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,12 +1,9 @@
 //! Part 1
 
-use super::{
-    common::{determine_loop_size, parse_keys, transform_subject_number_loop},
-    INPUT,
-};
+use super::common::{determine_loop_size, parse_keys, transform_subject_number_loop};
 
-pub fn run() -> usize {
-    let (card_key, door_key) = parse_keys(INPUT);
+pub fn run(input: &str) -> usize {
+    let (card_key, door_key) = parse_keys(input);
     let (card_ls, door_ls) = (determine_loop_size(card_key), determine_loop_size(door_key));
     let (card_ec, door_ec) = (
         transform_subject_number_loop(card_key, door_ls),
@@ -19,8 +16,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day25::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 19_414_467);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -23,3 +23,34 @@ pub mod day22;
 pub mod day23;
 pub mod day24;
 pub mod day25;
+
+use aoc_sx::core::solution::Registry;
+
+/// Register every solved day of this edition.
+pub fn register(registry: &mut Registry) {
+    registry.register(&day01::Day);
+    registry.register(&day02::Day);
+    registry.register(&day03::Day);
+    registry.register(&day04::Day);
+    registry.register(&day05::Day);
+    registry.register(&day06::Day);
+    registry.register(&day07::Day);
+    registry.register(&day08::Day);
+    registry.register(&day09::Day);
+    registry.register(&day10::Day);
+    registry.register(&day11::Day);
+    registry.register(&day12::Day);
+    registry.register(&day13::Day);
+    registry.register(&day14::Day);
+    registry.register(&day15::Day);
+    registry.register(&day16::Day);
+    registry.register(&day17::Day);
+    registry.register(&day18::Day);
+    registry.register(&day19::Day);
+    registry.register(&day20::Day);
+    registry.register(&day21::Day);
+    registry.register(&day22::Day);
+    registry.register(&day23::Day);
+    registry.register(&day24::Day);
+    registry.register(&day25::Day);
+}
@@ -2,16 +2,26 @@
 
 use aoc_sx::algo::parse::parse_lines;
 
-use super::{common::count_increments_three, INPUT};
+use super::common::count_increments_three;
 
-pub fn run() -> usize {
-    count_increments_three(&parse_lines(INPUT))
+pub fn run(input: &str) -> usize {
+    count_increments_three(&parse_lines(input))
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day01::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1571)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
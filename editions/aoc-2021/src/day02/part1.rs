@@ -2,16 +2,26 @@
 
 use aoc_sx::algo::parse::parse_str_lines;
 
-use super::{common::Submarine, INPUT};
+use super::common::Submarine;
 
-pub fn run() -> i32 {
-    Submarine::from_moves(&parse_str_lines(INPUT)).score()
+pub fn run(input: &str) -> i32 {
+    Submarine::from_moves(&parse_str_lines(input)).score()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day02::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 2_102_357)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -2,17 +2,27 @@
 
 use aoc_sx::algo::parse::parse_str_lines;
 
-use super::{common::BitAnalyzer, INPUT};
+use super::common::BitAnalyzer;
 
-pub fn run() -> u32 {
-    let (gamma, epsilon) = BitAnalyzer::compute_gamma_and_epsilon(&parse_str_lines(INPUT));
+pub fn run(input: &str) -> u32 {
+    let (gamma, epsilon) = BitAnalyzer::compute_gamma_and_epsilon(&parse_str_lines(input));
     gamma * epsilon
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day03::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 3_959_450)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -2,10 +2,10 @@
 
 use aoc_sx::algo::parse::parse_str_lines;
 
-use super::{common::BitAnalyzer, INPUT};
+use super::common::BitAnalyzer;
 
-pub fn run() -> u32 {
-    let input = parse_str_lines(INPUT);
+pub fn run(input: &str) -> u32 {
+    let input = parse_str_lines(input);
     let oxygen = BitAnalyzer::compute_oxygen_generator_rating(&input);
     let scrubber = BitAnalyzer::compute_co2_scrubber_rating(&input);
     oxygen * scrubber
@@ -13,8 +13,18 @@ pub fn run() -> u32 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day03::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 7_440_311)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,19 +1,26 @@
 //! Part 2
 
-use super::{
-    common::{BingoParser, BingoPlayer},
-    INPUT,
-};
+use super::common::{BingoParser, BingoPlayer};
 
-pub fn run() -> u32 {
-    let (play, grids) = BingoParser::parse_play_and_grids(INPUT);
+pub fn run(input: &str) -> u32 {
+    let (play, grids) = BingoParser::parse_play_and_grids(input);
     BingoPlayer::play_waiting_for_last(play, grids)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day04::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 14_877)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
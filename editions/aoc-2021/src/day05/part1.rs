@@ -2,13 +2,10 @@
 
 use aoc_sx::algo::parse::parse_str_lines;
 
-use super::{
-    common::{LineCollisioner, LineParser},
-    INPUT,
-};
+use super::common::{LineCollisioner, LineParser};
 
-pub fn run() -> u32 {
-    let lines = LineParser::parse_lines(&parse_str_lines(INPUT));
+pub fn run(input: &str) -> u32 {
+    let lines = LineParser::parse_lines(&parse_str_lines(input));
     let lines = LineCollisioner::filter_horizontal_and_vertical(&lines);
     let collisions = LineCollisioner::scan_line_intersections(&lines);
     LineCollisioner::count_overlaps(collisions)
@@ -16,8 +13,18 @@ pub fn run() -> u32 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day05::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 4_993)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
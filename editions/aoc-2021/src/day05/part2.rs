@@ -2,21 +2,28 @@
 
 use aoc_sx::algo::parse::parse_str_lines;
 
-use super::{
-    common::{LineCollisioner, LineParser},
-    INPUT,
-};
+use super::common::{LineCollisioner, LineParser};
 
-pub fn run() -> u32 {
-    let lines = LineParser::parse_lines(&parse_str_lines(INPUT));
+pub fn run(input: &str) -> u32 {
+    let lines = LineParser::parse_lines(&parse_str_lines(input));
     let collisions = LineCollisioner::scan_line_intersections(&lines);
     LineCollisioner::count_overlaps(collisions)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day05::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 21_101)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
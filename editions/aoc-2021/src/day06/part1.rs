@@ -1,17 +1,27 @@
 //! Part 1
 
-use super::{common::FishSchool, INPUT};
+use super::common::FishSchool;
 
-pub fn run() -> usize {
-    let mut school = FishSchool::from(INPUT);
+pub fn run(input: &str) -> usize {
+    let mut school = FishSchool::from(input);
     school.step_count(80);
     school.count()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day06::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 396_210)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 2
 
-use super::{common::FishSchool, INPUT};
+use super::common::FishSchool;
 
-pub fn run() -> usize {
-    let mut school = FishSchool::from(INPUT);
+pub fn run(input: &str) -> usize {
+    let mut school = FishSchool::from(input);
     school.step_count(256);
     school.count()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day06::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1_770_823_541_496)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 1
 
-use super::{common::CrabSwarm, INPUT};
+use super::common::CrabSwarm;
 
-pub fn run() -> u32 {
-    let swarm = CrabSwarm::from(INPUT);
+pub fn run(input: &str) -> u32 {
+    let swarm = CrabSwarm::from(input);
     let (_pos, sum) = swarm.min_cost_for_alignment();
     sum
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day07::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 347_011)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 2
 
-use super::{common::CrabSwarm, INPUT};
+use super::common::CrabSwarm;
 
-pub fn run() -> u32 {
-    let swarm = CrabSwarm::from(INPUT);
+pub fn run(input: &str) -> u32 {
+    let swarm = CrabSwarm::from(input);
     let (_pos, sum) = swarm.min_cost_for_alignment_with_sum();
     sum
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day07::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 98_363_777)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
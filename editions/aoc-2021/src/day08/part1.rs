@@ -2,20 +2,27 @@
 
 use aoc_sx::algo::parse::parse_lines;
 
-use super::{
-    common::{PatternCounter, PatternLine},
-    INPUT,
-};
+use super::common::{PatternCounter, PatternLine};
 
-pub fn run() -> usize {
-    let lines: Vec<PatternLine> = parse_lines(INPUT);
+pub fn run(input: &str) -> usize {
+    let lines: Vec<PatternLine> = parse_lines(input);
     PatternCounter::count_unambiguous_output_patterns(&lines)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day08::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 412)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
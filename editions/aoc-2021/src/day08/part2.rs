@@ -2,13 +2,10 @@
 
 use aoc_sx::algo::parse::parse_lines;
 
-use super::{
-    common::{find_solution, PatternLine},
-    INPUT,
-};
+use super::common::{find_solution, PatternLine};
 
-pub fn run() -> usize {
-    let lines: Vec<PatternLine> = parse_lines(INPUT);
+pub fn run(input: &str) -> usize {
+    let lines: Vec<PatternLine> = parse_lines(input);
     lines
         .iter()
         .map(|line| {
@@ -23,8 +20,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day08::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 978_171)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 1
 
-use super::{common::HeightMap, INPUT};
+use super::common::HeightMap;
 
-pub fn run() -> u32 {
-    let hm = HeightMap::from(INPUT);
+pub fn run(input: &str) -> u32 {
+    let hm = HeightMap::from(input);
     let points = hm.find_low_points();
     hm.compute_risk_level_sum(&points)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day09::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 631)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 2
 
-use super::{common::HeightMap, INPUT};
+use super::common::HeightMap;
 
-pub fn run() -> usize {
-    let hm = HeightMap::from(INPUT);
+pub fn run(input: &str) -> usize {
+    let hm = HeightMap::from(input);
     let basins = hm.find_basin_positions();
     hm.largest_basin_mul(basins)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day09::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 821_560)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
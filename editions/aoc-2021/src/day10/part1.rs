@@ -2,17 +2,27 @@
 
 use aoc_sx::{algo::parse::parse_str_lines, tap::Pipe};
 
-use super::{common::NavParser, INPUT};
+use super::common::NavParser;
 
-pub fn run() -> u32 {
-    NavParser::check_errors_on_lines(&parse_str_lines(INPUT))
+pub fn run(input: &str) -> u32 {
+    NavParser::check_errors_on_lines(&parse_str_lines(input))
         .pipe(|x| NavParser::count_errors_score(&x))
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day10::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 268_845)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -2,10 +2,10 @@
 
 use aoc_sx::{algo::parse::parse_str_lines, tap::Pipe};
 
-use super::{common::NavParser, INPUT};
+use super::common::NavParser;
 
-pub fn run() -> u64 {
-    NavParser::filter_incomplete_lines(&parse_str_lines(INPUT))
+pub fn run(input: &str) -> u64 {
+    NavParser::filter_incomplete_lines(&parse_str_lines(input))
         .into_iter()
         .map(NavParser::autocomplete_line)
         .map(|line| NavParser::count_autocomplete_score(&line))
@@ -15,8 +15,18 @@ pub fn run() -> u64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day10::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 4_038_824_534)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
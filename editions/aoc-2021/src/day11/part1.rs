@@ -2,13 +2,10 @@
 
 use aoc_sx::algo::fs::get_debug_path;
 
-use super::{
-    common::{save_grid_to_disk, Grid},
-    INPUT,
-};
+use super::common::{save_grid_to_disk, Grid};
 
-pub fn run() -> usize {
-    let mut grid = Grid::from(INPUT);
+pub fn run(input: &str) -> usize {
+    let mut grid = Grid::from(input);
     let answer = grid.step_for(100);
 
     let path = get_debug_path().join("aoc2021-day11.png");
@@ -19,8 +16,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day11::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1_603)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
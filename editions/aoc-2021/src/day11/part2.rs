@@ -1,16 +1,26 @@
 //! Part 2
 
-use super::{common::Grid, INPUT};
+use super::common::Grid;
 
-pub fn run() -> usize {
-    let mut grid = Grid::from(INPUT);
+pub fn run(input: &str) -> usize {
+    let mut grid = Grid::from(input);
     grid.step_until_all_flashing()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day11::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 222)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -2,13 +2,10 @@
 
 use aoc_sx::algo::{fs::get_debug_path, parse::parse_str_lines};
 
-use super::{
-    common::{save_graph_to_disk, Graph},
-    INPUT,
-};
+use super::common::{save_graph_to_disk, Graph};
 
-pub fn run() -> usize {
-    let graph = Graph::from(&parse_str_lines(INPUT)[..]);
+pub fn run(input: &str) -> usize {
+    let graph = Graph::from(&parse_str_lines(input)[..]);
     let path = get_debug_path().join("aoc2021-day12.dot");
     save_graph_to_disk(&graph, &path);
 
@@ -17,8 +14,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day12::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 5_457)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 1
 
-use super::{common::TransparentPaper, INPUT};
+use super::common::TransparentPaper;
 
-pub fn run() -> usize {
-    let paper = TransparentPaper::from(INPUT);
+pub fn run(input: &str) -> usize {
+    let paper = TransparentPaper::from(input);
     let paper = paper.fold_next_rule().unwrap();
     paper.count_dots()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day13::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 655)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,14 +1,11 @@
 //! Part 2
 
-use aoc_sx::{algo::fs::get_debug_path, indoc::indoc};
+use aoc_sx::algo::{fs::get_debug_path, ocr::read_letters};
 
-use super::{
-    common::{save_paper_to_disk, TransparentPaper},
-    INPUT,
-};
+use super::common::{save_paper_to_disk, TransparentPaper};
 
-pub fn run() -> String {
-    let mut paper = TransparentPaper::from(INPUT);
+pub fn run(input: &str) -> String {
+    let mut paper = TransparentPaper::from(input);
     let path = get_debug_path().join("aoc2021-day13.png");
 
     while let Some(p) = paper.fold_next_rule() {
@@ -17,26 +14,25 @@ pub fn run() -> String {
 
     save_paper_to_disk(&paper, &path);
 
-    // Yes, answer is hardcoded, because I don't have time to apply OCR on the output. 😅
-    assert_eq!(
-        paper.to_string(),
-        indoc! {"
-        ..##.###..####..##..#..#..##..#..#.###..
-        ...#.#..#....#.#..#.#..#.#..#.#..#.#..#.
-        ...#.#..#...#..#....#..#.#..#.#..#.#..#.
-        ...#.###...#...#....#..#.####.#..#.###..
-        #..#.#....#....#..#.#..#.#..#.#..#.#.#..
-        .##..#....####..##...##..#..#..##..#..#."
-        }
-    );
-
-    "JPZCUAUR".to_string()
+    // Fall back to the paper itself when some letter is unknown
+    let paper = paper.to_string();
+    read_letters(&paper).unwrap_or(paper)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day13::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), "JPZCUAUR")
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
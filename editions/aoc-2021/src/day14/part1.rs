@@ -1,12 +1,9 @@
 //! Part 1
 
-use super::{
-    common::{Polymer, PolymerSum},
-    INPUT,
-};
+use super::common::{Polymer, PolymerSum};
 
-pub fn run() -> u64 {
-    let polymer = Polymer::from(INPUT);
+pub fn run(input: &str) -> u64 {
+    let polymer = Polymer::from(input);
     let mut chain = PolymerSum::new(&polymer);
     chain.step_for(10);
     chain.get_common_score()
@@ -14,8 +11,18 @@ pub fn run() -> u64 {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day14::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 3_284)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,16 +1,26 @@
 //! Part 1
 
-use super::{common::Cave, INPUT};
+use super::common::Cave;
 
-pub fn run() -> u64 {
-    let cave = Cave::from(INPUT);
+pub fn run(input: &str) -> u64 {
+    let cave = Cave::from(input);
     cave.get_lower_risk_path_sum()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day15::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 621)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,19 +1,26 @@
 //! Part 2
 
-use super::{
-    common::{BinUtils, Packet},
-    INPUT,
-};
+use super::common::{BinUtils, Packet};
 
-pub fn run() -> usize {
-    let mut bin = BinUtils::bin_from_hex_string(INPUT.trim());
+pub fn run(input: &str) -> usize {
+    let mut bin = BinUtils::bin_from_hex_string(input.trim());
     Packet::from_bin(&mut bin).get_value()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day16::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 194_435_634_456)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -14,3 +14,25 @@ pub mod day13;
 pub mod day14;
 pub mod day15;
 pub mod day16;
+
+use aoc_sx::core::solution::Registry;
+
+/// Register every solved day of this edition.
+pub fn register(registry: &mut Registry) {
+    registry.register(&day01::Day);
+    registry.register(&day02::Day);
+    registry.register(&day03::Day);
+    registry.register(&day04::Day);
+    registry.register(&day05::Day);
+    registry.register(&day06::Day);
+    registry.register(&day07::Day);
+    registry.register(&day08::Day);
+    registry.register(&day09::Day);
+    registry.register(&day10::Day);
+    registry.register(&day11::Day);
+    registry.register(&day12::Day);
+    registry.register(&day13::Day);
+    registry.register(&day14::Day);
+    registry.register(&day15::Day);
+    registry.register(&day16::Day);
+}
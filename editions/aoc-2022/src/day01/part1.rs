@@ -2,16 +2,26 @@
 
 use std::str::FromStr;
 
-use super::{common::ElfCalorieReader, INPUT};
+use super::common::ElfCalorieReader;
 
-pub fn run() -> u32 {
-    ElfCalorieReader::from_str(INPUT).unwrap().max_calories()
+pub fn run(input: &str) -> u32 {
+    ElfCalorieReader::from_str(input).unwrap().max_calories()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day01::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 66_616)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
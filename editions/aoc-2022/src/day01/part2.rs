@@ -2,18 +2,28 @@
 
 use std::str::FromStr;
 
-use super::{common::ElfCalorieReader, INPUT};
+use super::common::ElfCalorieReader;
 
-pub fn run() -> u32 {
-    ElfCalorieReader::from_str(INPUT)
+pub fn run(input: &str) -> u32 {
+    ElfCalorieReader::from_str(input)
         .unwrap()
         .top_three_max_calories()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day01::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 199_172)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -2,16 +2,26 @@
 
 use std::str::FromStr;
 
-use super::{common::GameParser, INPUT};
+use super::common::GameParser;
 
-pub fn run() -> u32 {
-    GameParser::from_str(INPUT).unwrap().total_score()
+pub fn run(input: &str) -> u32 {
+    GameParser::from_str(input).unwrap().total_score()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day02::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 12_679)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
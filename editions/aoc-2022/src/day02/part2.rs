@@ -2,18 +2,28 @@
 
 use std::str::FromStr;
 
-use super::{common::GameParser, INPUT};
+use super::common::GameParser;
 
-pub fn run() -> u32 {
-    GameParser::from_str(INPUT)
+pub fn run(input: &str) -> u32 {
+    GameParser::from_str(input)
         .unwrap()
         .total_score_from_outcome()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day02::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 14_470)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
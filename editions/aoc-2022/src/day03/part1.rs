@@ -2,16 +2,26 @@
 
 use std::str::FromStr;
 
-use super::{common::RucksackParser, INPUT};
+use super::common::RucksackParser;
 
-pub fn run() -> u32 {
-    RucksackParser::from_str(INPUT).unwrap().priority_sum()
+pub fn run(input: &str) -> u32 {
+    RucksackParser::from_str(input).unwrap().priority_sum()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day03::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 7_553)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -2,18 +2,28 @@
 
 use std::str::FromStr;
 
-use super::{common::RucksackParser, INPUT};
+use super::common::RucksackParser;
 
-pub fn run() -> u32 {
-    RucksackParser::from_str(INPUT)
+pub fn run(input: &str) -> u32 {
+    RucksackParser::from_str(input)
         .unwrap()
         .group_priority_sum()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day03::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 2_758)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -2,16 +2,26 @@
 
 use std::str::FromStr;
 
-use super::{common::Assignments, INPUT};
+use super::common::Assignments;
 
-pub fn run() -> usize {
-    Assignments::from_str(INPUT).unwrap().overlapping_count()
+pub fn run(input: &str) -> usize {
+    Assignments::from_str(input).unwrap().overlapping_count()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day04::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 893)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 1
 
-use super::{common::Ship, INPUT};
+use super::common::Ship;
 
-pub fn run() -> String {
-    let mut ship = Ship::from_input(INPUT);
+pub fn run(input: &str) -> String {
+    let mut ship = Ship::from_input(input);
     ship.apply_internal_procedures();
     ship.get_stack_tops_to_string()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day05::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), "LJSVLTWQM")
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,12 +1,9 @@
 //! Part 2
 
-use super::{
-    common::{CraneType, Ship},
-    INPUT,
-};
+use super::common::{CraneType, Ship};
 
-pub fn run() -> String {
-    let mut ship = Ship::from_input(INPUT);
+pub fn run(input: &str) -> String {
+    let mut ship = Ship::from_input(input);
     ship.set_crane_type(CraneType::CrateMover9001);
     ship.apply_internal_procedures();
     ship.get_stack_tops_to_string()
@@ -14,8 +11,18 @@ pub fn run() -> String {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day05::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), "BRQWDBBJM")
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
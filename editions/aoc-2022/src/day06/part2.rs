@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::DataStream, INPUT};
+use super::common::DataStream;
 
-pub fn run() -> usize {
-    DataStream::from_input(INPUT).start_of_packet_marker(14)
+pub fn run(input: &str) -> usize {
+    DataStream::from_input(input).start_of_packet_marker(14)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day06::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 3613)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::ShellSession, INPUT};
+use super::common::ShellSession;
 
-pub fn run() -> usize {
-    ShellSession::from_input(INPUT).sum_directories_total_size_less_than(100_000)
+pub fn run(input: &str) -> usize {
+    ShellSession::from_input(input).sum_directories_total_size_less_than(100_000)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day07::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1_432_936)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
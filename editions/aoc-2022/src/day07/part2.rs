@@ -1,17 +1,27 @@
 //! Part 2
 
-use super::{common::ShellSession, INPUT};
+use super::common::ShellSession;
 
-pub fn run() -> usize {
-    ShellSession::from_input(INPUT)
+pub fn run(input: &str) -> usize {
+    ShellSession::from_input(input)
         .find_smallest_directory_to_free_space()
         .total_size()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day07::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 272_298)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -5,3 +5,16 @@ pub mod day04;
 pub mod day05;
 pub mod day06;
 pub mod day07;
+
+use aoc_sx::core::solution::Registry;
+
+/// Register every solved day of this edition.
+pub fn register(registry: &mut Registry) {
+    registry.register(&day01::Day);
+    registry.register(&day02::Day);
+    registry.register(&day03::Day);
+    registry.register(&day04::Day);
+    registry.register(&day05::Day);
+    registry.register(&day06::Day);
+    registry.register(&day07::Day);
+}
@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::CalibrationDocument, INPUT};
+use super::common::CalibrationDocument;
 
-pub fn run() -> u32 {
-    CalibrationDocument::from_input(INPUT).total_calibration_value()
+pub fn run(input: &str) -> u32 {
+    CalibrationDocument::from_input(input).total_calibration_value()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day01::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 54388)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 2
 
-use super::{common::CalibrationDocument, INPUT};
+use super::common::CalibrationDocument;
 
-pub fn run() -> u32 {
-    CalibrationDocument::from_input(INPUT)
+pub fn run(input: &str) -> u32 {
+    CalibrationDocument::from_input(input)
         .with_spelled_digits_converted_to_integers()
         .total_calibration_value()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day01::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 53515)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,12 +1,9 @@
 //! Part 1
 
-use super::{
-    common::{CubeSet, GameList},
-    INPUT,
-};
+use super::common::{CubeSet, GameList};
 
-pub fn run() -> usize {
-    let game_list = GameList::from_input(INPUT);
+pub fn run(input: &str) -> usize {
+    let game_list = GameList::from_input(input);
     let bag = CubeSet::from_input("12 red, 13 green, 14 blue");
 
     game_list.sum_of_possible_games_ids(&bag)
@@ -14,8 +11,18 @@ pub fn run() -> usize {
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day02::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 2283)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
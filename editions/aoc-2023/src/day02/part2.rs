@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::GameList, INPUT};
+use super::common::GameList;
 
-pub fn run() -> usize {
-    GameList::from_input(INPUT).sum_of_minimum_cubeset_powers()
+pub fn run(input: &str) -> usize {
+    GameList::from_input(input).sum_of_minimum_cubeset_powers()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day02::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 78_669)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
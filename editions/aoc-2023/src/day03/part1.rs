@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::Schematic, INPUT};
+use super::common::Schematic;
 
-pub fn run() -> u32 {
-    Schematic::from_input(INPUT).sum_of_part_numbers()
+pub fn run(input: &str) -> u32 {
+    Schematic::from_input(input).sum_of_part_numbers()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day03::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 546_563);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
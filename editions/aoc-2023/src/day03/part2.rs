@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::Schematic, INPUT};
+use super::common::Schematic;
 
-pub fn run() -> u32 {
-    Schematic::from_input(INPUT).sum_of_gear_ratios()
+pub fn run(input: &str) -> u32 {
+    Schematic::from_input(input).sum_of_gear_ratios()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day03::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 91_031_374)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -57,7 +57,10 @@ impl Scratchcard {
     }
 
     pub fn get_points_value(&self) -> usize {
-        2usize.pow((self.winning_numbers_count() - 1) as u32)
+        match self.winning_numbers_count() {
+            0 => 0,
+            count => 2usize.pow(count as u32 - 1),
+        }
     }
 }
 
@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::ScratchcardPile, INPUT};
+use super::common::ScratchcardPile;
 
-pub fn run() -> usize {
-    ScratchcardPile::from_input(INPUT).get_total_points_value()
+pub fn run(input: &str) -> usize {
+    ScratchcardPile::from_input(input).get_total_points_value()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day04::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 28_750)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::ScratchcardPile, INPUT};
+use super::common::ScratchcardPile;
 
-pub fn run() -> usize {
-    ScratchcardPile::from_input(INPUT).compute_cards_count()
+pub fn run(input: &str) -> usize {
+    ScratchcardPile::from_input(input).compute_cards_count()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day04::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 10_212_704);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
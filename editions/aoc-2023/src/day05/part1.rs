@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::Almanac, INPUT};
+use super::common::Almanac;
 
-pub fn run() -> usize {
-    Almanac::from_input(INPUT).get_lowest_location_numbers_from_seeds()
+pub fn run(input: &str) -> usize {
+    Almanac::from_input(input).get_lowest_location_numbers_from_seeds()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day05::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1_181_555_926)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
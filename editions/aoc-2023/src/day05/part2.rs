@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::Almanac, INPUT};
+use super::common::Almanac;
 
-pub fn run() -> usize {
-    Almanac::from_input(INPUT).get_lowest_location_numbers_using_reverse_bruteforce()
+pub fn run(input: &str) -> usize {
+    Almanac::from_input(input).get_lowest_location_numbers_using_reverse_bruteforce()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day05::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 37_806_486)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::BoatRaces, INPUT};
+use super::common::BoatRaces;
 
-pub fn run() -> usize {
-    BoatRaces::from_input(INPUT).product_ways_to_beat_records()
+pub fn run(input: &str) -> usize {
+    BoatRaces::from_input(input).product_ways_to_beat_records()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day06::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1_155_175)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
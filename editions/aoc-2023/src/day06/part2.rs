@@ -1,17 +1,27 @@
 //! Part 2
 
-use super::{common::BoatRaces, INPUT};
+use super::common::BoatRaces;
 
-pub fn run() -> usize {
-    BoatRaces::from_input(INPUT)
+pub fn run(input: &str) -> usize {
+    BoatRaces::from_input(input)
         .with_merged_distances()
         .product_ways_to_beat_records()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day06::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 35_961_505)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
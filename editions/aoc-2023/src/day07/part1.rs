@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::Game, INPUT};
+use super::common::Game;
 
-pub fn run() -> usize {
-    Game::from_input(INPUT).total_winnings()
+pub fn run(input: &str) -> usize {
+    Game::from_input(input).total_winnings()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day07::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 248_113_761)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::GameWithJoker, INPUT};
+use super::common::GameWithJoker;
 
-pub fn run() -> usize {
-    GameWithJoker::from_input(INPUT).total_winnings()
+pub fn run(input: &str) -> usize {
+    GameWithJoker::from_input(input).total_winnings()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day07::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 246_285_222)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
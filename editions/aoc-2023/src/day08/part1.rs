@@ -1,20 +1,27 @@
 //! Part 1
 
-use super::{
-    common::{CamelMap, MapWalker},
-    INPUT,
-};
+use super::common::{CamelMap, MapWalker};
 
-pub fn run() -> usize {
-    let map = CamelMap::from_input(INPUT);
+pub fn run(input: &str) -> usize {
+    let map = CamelMap::from_input(input);
     let walker = MapWalker::new();
     walker.steps_to_zzz(&map)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day08::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 11_567);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
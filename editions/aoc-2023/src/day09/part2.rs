@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::OasisReport, INPUT};
+use super::common::OasisReport;
 
-pub fn run() -> i32 {
-    OasisReport::from_input(INPUT).sum_previous_predicted_values()
+pub fn run(input: &str) -> i32 {
+    OasisReport::from_input(input).sum_previous_predicted_values()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day09::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1_066)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
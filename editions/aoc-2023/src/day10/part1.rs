@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::PipeMaze, INPUT};
+use super::common::PipeMaze;
 
-pub fn run() -> usize {
-    PipeMaze::from_input(INPUT).get_longest_position_in_loop()
+pub fn run(input: &str) -> usize {
+    PipeMaze::from_input(input).get_longest_position_in_loop()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day10::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 6_725)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
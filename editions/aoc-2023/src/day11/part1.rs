@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::Universe, INPUT};
+use super::common::Universe;
 
-pub fn run() -> usize {
-    Universe::from_input(INPUT).expand(1).sum_shortest_paths()
+pub fn run(input: &str) -> usize {
+    Universe::from_input(input).expand(1).sum_shortest_paths()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day11::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 10_033_566)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
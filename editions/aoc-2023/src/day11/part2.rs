@@ -1,17 +1,27 @@
 //! Part 2
 
-use super::{common::Universe, INPUT};
+use super::common::Universe;
 
-pub fn run() -> usize {
-    Universe::from_input(INPUT)
+pub fn run(input: &str) -> usize {
+    Universe::from_input(input)
         .expand(1_000_000)
         .sum_shortest_paths()
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day11::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 560_822_911_938)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -9,3 +9,20 @@ pub mod day08;
 pub mod day09;
 pub mod day10;
 pub mod day11;
+
+use aoc_sx::core::solution::Registry;
+
+/// Register every solved day of this edition.
+pub fn register(registry: &mut Registry) {
+    registry.register(&day01::Day);
+    registry.register(&day02::Day);
+    registry.register(&day03::Day);
+    registry.register(&day04::Day);
+    registry.register(&day05::Day);
+    registry.register(&day06::Day);
+    registry.register(&day07::Day);
+    registry.register(&day08::Day);
+    registry.register(&day09::Day);
+    registry.register(&day10::Day);
+    registry.register(&day11::Day);
+}
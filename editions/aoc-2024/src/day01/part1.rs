@@ -1,17 +1,27 @@
 //! Part 1
 
-use super::{common::Sorter, INPUT};
+use super::common::Sorter;
 
-pub fn run() -> usize {
-    let (mut v1, mut v2) = Sorter::from_input(INPUT);
+pub fn run(input: &str) -> usize {
+    let (mut v1, mut v2) = Sorter::from_input(input);
     Sorter::sort_lists(&mut v1, &mut v2);
     Sorter::sum_distances(Sorter::distances(&v1, &v2)) as usize
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day01::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 2_285_373)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,16 +1,26 @@
 //! Part 2
 
-use super::{common::Sorter, INPUT};
+use super::common::Sorter;
 
-pub fn run() -> usize {
-    let (v1, v2) = Sorter::from_input(INPUT);
+pub fn run(input: &str) -> usize {
+    let (v1, v2) = Sorter::from_input(input);
     Sorter::total_similarity_score(&v1, &v2) as usize
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day01::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 21_142_653);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,18 +1,25 @@
 //! Part 1
 
-use super::{
-    common::{Analyzer, Puzzle},
-    INPUT,
-};
+use super::common::{Analyzer, Puzzle};
 
-pub fn run() -> usize {
-    Analyzer::count_safe_reports(&Puzzle::from_input(INPUT)) as usize
+pub fn run(input: &str) -> usize {
+    Analyzer::count_safe_reports(&Puzzle::from_input(input)) as usize
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day02::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 624);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,15 +1,25 @@
 //! Part 1
 
-use super::{common::Puzzle, INPUT};
+use super::common::Puzzle;
 
-pub fn run() -> usize {
-    Puzzle::from_input(INPUT).compute_multiplications() as usize
+pub fn run(input: &str) -> usize {
+    Puzzle::from_input(input).compute_multiplications() as usize
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day03::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 178_886_550)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
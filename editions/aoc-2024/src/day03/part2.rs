@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::Puzzle, INPUT};
+use super::common::Puzzle;
 
-pub fn run() -> usize {
-    Puzzle::from_input_with_conditionals(INPUT).compute_multiplications() as usize
+pub fn run(input: &str) -> usize {
+    Puzzle::from_input_with_conditionals(input).compute_multiplications() as usize
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day03::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 87_163_705);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
@@ -1,17 +1,27 @@
 //! Part 1
 
-use super::{common::Puzzle, INPUT};
+use super::common::Puzzle;
 
-pub fn run() -> usize {
-    Puzzle::from_input(INPUT)
+pub fn run(input: &str) -> usize {
+    Puzzle::from_input(input)
         .to_square_window()
         .count_word_in_window("XMAS") as usize
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day04::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 2507);
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::First, &input, super::run(&input));
     }
 }
@@ -1,15 +1,25 @@
 //! Part 2
 
-use super::{common::Puzzle, INPUT};
+use super::common::Puzzle;
 
-pub fn run() -> usize {
-    Puzzle::from_input(INPUT).count_x_mas() as usize
+pub fn run(input: &str) -> usize {
+    Puzzle::from_input(input).count_x_mas() as usize
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc_sx::core::{
+        exercise::ExercisePart,
+        testing::{check_answer, test_input},
+    };
+
+    use crate::day04::Day;
+
     #[test]
     fn run() {
-        assert_eq!(super::run(), 1969)
+        let Some(input) = test_input::<Day>() else {
+            return;
+        };
+        check_answer::<Day>(ExercisePart::Second, &input, super::run(&input));
     }
 }
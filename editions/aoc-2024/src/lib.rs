@@ -2,3 +2,13 @@ pub mod day01;
 pub mod day02;
 pub mod day03;
 pub mod day04;
+
+use aoc_sx::core::solution::Registry;
+
+/// Register every solved day of this edition.
+pub fn register(registry: &mut Registry) {
+    registry.register(&day01::Day);
+    registry.register(&day02::Day);
+    registry.register(&day03::Day);
+    registry.register(&day04::Day);
+}
@@ -1,11 +1,13 @@
 use std::path::PathBuf;
 
+use aoc_sx_core::input::workspace_root;
+
+const DEBUG_DIR_ENV: &str = "AOC_DEBUG_DIR";
+
+/// Debug output folder, from the `AOC_DEBUG_DIR` variable or `debug` at the workspace root.
 pub fn get_debug_path() -> PathBuf {
-    std::env::current_dir()
-        .unwrap()
-        .parent()
-        .unwrap()
-        .parent()
-        .unwrap()
-        .join("debug")
+    match std::env::var_os(DEBUG_DIR_ENV) {
+        Some(dir) => PathBuf::from(dir),
+        None => workspace_root().join("debug"),
+    }
 }
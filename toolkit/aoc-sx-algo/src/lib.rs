@@ -1,3 +1,4 @@
 pub mod fs;
 pub mod math;
+pub mod ocr;
 pub mod parse;
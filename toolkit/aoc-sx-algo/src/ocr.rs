@@ -0,0 +1,73 @@
+//! Read the capital letters drawn by some exercises, in the 4x6 font of the event.
+
+const LETTER_WIDTH: usize = 4;
+const LETTER_HEIGHT: usize = 6;
+
+/// Known letters, row by row.
+const LETTERS: [(char, [&str; LETTER_HEIGHT]); 16] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Read letters from a screen of `#` and `.`, each letter followed by an empty column.
+///
+/// Returns `None` if the screen is not 6 rows high or has an unknown letter.
+pub fn read_letters(screen: &str) -> Option<String> {
+    let rows = screen.lines().map(str::as_bytes).collect::<Vec<_>>();
+    let width = rows.iter().map(|r| r.len()).max()?;
+    if rows.len() != LETTER_HEIGHT {
+        return None;
+    }
+
+    (0..width)
+        .step_by(LETTER_WIDTH + 1)
+        .map(|x| {
+            let cell = |row: &[u8], dx: usize| match row.get(x + dx) {
+                Some(b'#') => '#',
+                _ => '.',
+            };
+
+            LETTERS
+                .iter()
+                .find(|(_, glyph)| {
+                    rows.iter().zip(glyph).all(|(row, line)| {
+                        line.chars().enumerate().all(|(dx, c)| cell(row, dx) == c)
+                    })
+                })
+                .map(|(letter, _)| *letter)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::read_letters;
+
+    #[test]
+    fn read() {
+        let screen = "\
+            #..#.####.\n\
+            #..#.#....\n\
+            ####.###..\n\
+            #..#.#....\n\
+            #..#.#....\n\
+            #..#.####.";
+        assert_eq!(read_letters(screen).as_deref(), Some("HE"));
+        assert_eq!(read_letters("#..#\n.##."), None);
+        assert_eq!(read_letters(&screen.replace("####.###", "#.##.###")), None);
+    }
+}
@@ -0,0 +1,287 @@
+//! Solution benchmarks.
+
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_sx_core::{input::workspace_root, solution::RegisteredSolution};
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{format_duration, print_table, run_solution};
+
+const HISTORY_FILE_NAME: &str = "bench_output.txt";
+
+/// Benchmark options.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+/// Benchmark of a single day, durations in nanoseconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+/// A benchmark run, as stored in the history file.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BenchRecord {
+    pub timestamp: u64,
+    pub iterations: usize,
+    pub results: Vec<DayBench>,
+}
+
+/// Comparison of a day benchmark with the previous one.
+#[derive(Debug, Serialize)]
+pub struct DayComparison {
+    #[serde(flatten)]
+    pub bench: DayBench,
+    pub previous_median_ns: Option<u64>,
+    /// Median change, in percent.
+    pub change: Option<f64>,
+    pub regressed: bool,
+}
+
+/// Benchmark history, stored as one JSON record per line.
+#[derive(Debug)]
+pub struct BenchHistory {
+    path: PathBuf,
+}
+
+impl BenchHistory {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// History file at the workspace root.
+    pub fn from_workspace() -> Self {
+        Self::new(workspace_root().join(HISTORY_FILE_NAME))
+    }
+
+    /// Latest benchmark of each day over every recorded run, skipping lines which are not records
+    /// (legacy format, truncated writes).
+    pub fn baseline(&self) -> Result<Vec<DayBench>> {
+        if !self.path.exists() {
+            return Ok(vec![]);
+        }
+
+        let content = std::fs::read_to_string(&self.path)?;
+        let mut skipped = 0;
+        let mut latest = BTreeMap::new();
+        for line in content.lines().filter(|l| !l.trim().is_empty()) {
+            match serde_json::from_str::<BenchRecord>(line) {
+                Ok(record) => {
+                    for bench in record.results {
+                        latest.insert((bench.year, bench.day), bench);
+                    }
+                }
+                Err(_) => skipped += 1,
+            }
+        }
+
+        if skipped > 0 {
+            tracing::warn!("Skipped {skipped} unreadable line(s) of {:?}", self.path);
+        }
+
+        Ok(latest.into_values().collect())
+    }
+
+    /// Append a run to the history.
+    pub fn append(&self, record: &BenchRecord) -> Result<()> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+        Ok(())
+    }
+}
+
+/// Benchmark a solution over multiple iterations, after a warm-up.
+pub fn bench_solution(
+    solution: &dyn RegisteredSolution,
+    input: &str,
+    options: BenchOptions,
+) -> DayBench {
+    for _ in 0..options.warmup {
+        run_solution(solution, input, None);
+    }
+
+    let mut durations = (0..options.iterations.max(1))
+        .map(|_| run_solution(solution, input, None).total_duration())
+        .collect::<Vec<_>>();
+    durations.sort();
+
+    DayBench {
+        year: solution.year().as_u16(),
+        day: solution.day().as_u8(),
+        median_ns: as_nanos(median(&durations)),
+        min_ns: as_nanos(durations[0]),
+        max_ns: as_nanos(durations[durations.len() - 1]),
+    }
+}
+
+/// Build a record from day benchmarks.
+pub fn new_record(results: Vec<DayBench>, options: BenchOptions) -> BenchRecord {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    BenchRecord {
+        timestamp,
+        iterations: options.iterations,
+        results,
+    }
+}
+
+/// Compare day benchmarks with previous ones, flagging median regressions above a threshold (in percent).
+pub fn compare(results: &[DayBench], baseline: &[DayBench], threshold: f64) -> Vec<DayComparison> {
+    results
+        .iter()
+        .map(|bench| {
+            let previous_median_ns = baseline
+                .iter()
+                .find(|b| b.year == bench.year && b.day == bench.day)
+                .map(|b| b.median_ns);
+            let change = previous_median_ns
+                .filter(|p| *p > 0)
+                .map(|p| (bench.median_ns as f64 - p as f64) / p as f64 * 100.0);
+
+            DayComparison {
+                bench: bench.clone(),
+                previous_median_ns,
+                change,
+                regressed: change.map_or(false, |c| c > threshold),
+            }
+        })
+        .collect()
+}
+
+/// Print comparisons as a table.
+pub fn print_comparison_table(comparisons: &[DayComparison]) {
+    let header = ["Year", "Day", "Median", "Min", "Max", "Previous", "Change"]
+        .map(String::from)
+        .to_vec();
+    let mut rows = vec![header];
+
+    for c in comparisons {
+        rows.push(vec![
+            c.bench.year.to_string(),
+            c.bench.day.to_string(),
+            format_nanos(c.bench.median_ns),
+            format_nanos(c.bench.min_ns),
+            format_nanos(c.bench.max_ns),
+            c.previous_median_ns.map(format_nanos).unwrap_or_default(),
+            match (c.change, c.regressed) {
+                (Some(change), true) => format!("{change:+.1}% (regression)"),
+                (Some(change), false) => format!("{change:+.1}%"),
+                (None, _) => String::new(),
+            },
+        ]);
+    }
+
+    print_table(&rows);
+
+    let regressions = comparisons.iter().filter(|c| c.regressed).count();
+    if regressions > 0 {
+        println!("\n{regressions} regression(s) detected.");
+    }
+}
+
+fn median(sorted: &[Duration]) -> Duration {
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+fn as_nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+fn format_nanos(nanos: u64) -> String {
+    format_duration(Duration::from_nanos(nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare, BenchHistory, BenchRecord, DayBench};
+
+    fn bench(day: u8, median_ns: u64) -> DayBench {
+        DayBench {
+            year: 2020,
+            day,
+            median_ns,
+            min_ns: median_ns,
+            max_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn compare_flags_regressions() {
+        let comparisons = compare(
+            &[bench(1, 1_050), bench(2, 1_500), bench(3, 10)],
+            &[bench(1, 1_000), bench(2, 1_000)],
+            10.0,
+        );
+
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert_eq!(comparisons[1].change, Some(50.0));
+        assert_eq!(comparisons[2].previous_median_ns, None);
+        assert!(!comparisons[2].regressed);
+    }
+
+    #[test]
+    fn baseline_from_latest_runs() {
+        let path = std::env::temp_dir().join(format!("aoc-sx-bench-{}.txt", std::process::id()));
+        let history = BenchHistory::new(&path);
+        assert!(history.baseline().unwrap().is_empty());
+
+        let record = |results| BenchRecord {
+            timestamp: 0,
+            iterations: 10,
+            results,
+        };
+        // A full run, then a filtered one
+        history
+            .append(&record(vec![bench(1, 1_000), bench(2, 1_000)]))
+            .unwrap();
+        history.append(&record(vec![bench(1, 2_000)])).unwrap();
+
+        // Legacy text output, then a truncated record
+        let mut content = std::fs::read_to_string(&path).unwrap();
+        content.push_str("Day 01 - Part 1: 1.2ms\n{\"timestamp\": 3, \"iter\n");
+        std::fs::write(&path, content).unwrap();
+
+        let baseline = history.baseline().unwrap();
+        let medians = baseline
+            .iter()
+            .map(|b| (b.day, b.median_ns))
+            .collect::<Vec<_>>();
+        assert_eq!(medians, [(1, 2_000), (2, 1_000)]);
+
+        // The next full run is still compared for every day
+        let comparisons = compare(&[bench(1, 2_000), bench(2, 1_500)], &baseline, 10.0);
+        assert!(!comparisons[0].regressed && comparisons[1].regressed);
+
+        std::fs::write(&path, "Day 01 - Part 1: 1.2ms\n").unwrap();
+        assert!(history.baseline().unwrap().is_empty());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,19 +1,208 @@
-//! Config.
+//! Config, layered from the user config file, the project `aoc-sx.toml`, `AOC_*` variables and flags.
 
-use color_eyre::Result;
-use figment::{providers::Env, Figment};
-use serde::Deserialize;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Deserialize)]
+use aoc_sx_core::input::{default_inputs_dir, workspace_root};
+use aoc_sx_webclient::cache::Cache;
+use color_eyre::{eyre::eyre, Result};
+use figment::{
+    providers::{Env, Format, Serialized, Toml},
+    Figment,
+};
+use serde::{Deserialize, Serialize};
+
+const PROJECT_CONFIG_FILE: &str = "aoc-sx.toml";
+const EDITIONS_DIR_NAME: &str = "editions";
+const DEBUG_DIR_ENV: &str = "AOC_DEBUG_DIR";
+
+#[derive(Debug, Default, Deserialize)]
 pub struct Config {
     pub session_token: Option<String>,
+    /// File containing the session token.
+    pub session_token_file: Option<PathBuf>,
+    /// Folder of the edition crates.
+    pub editions_dir: Option<PathBuf>,
+    /// Folder of the HTTP cache.
+    pub cache_dir: Option<PathBuf>,
+    /// Folder of the puzzle inputs.
+    pub inputs_dir: Option<PathBuf>,
+    /// Folder where solutions write their debug output.
+    pub debug_dir: Option<PathBuf>,
+    /// Contact string included in the User-Agent header.
+    pub user_agent_contact: Option<String>,
+    /// Server URL, e.g. a local mock.
+    pub base_url: Option<String>,
+    /// Folder where responses are recorded as fixtures.
+    pub record_dir: Option<PathBuf>,
+    /// Folder of recorded responses to serve instead of reaching the server.
+    pub replay_dir: Option<PathBuf>,
+    /// Default private leaderboard ID.
+    pub leaderboard_id: Option<u64>,
+}
+
+/// Config flags, overriding every other source.
+#[derive(Debug, Default, clap::Args, Serialize)]
+pub struct ConfigArgs {
+    /// Config file, instead of the project `aoc-sx.toml`.
+    #[arg(long, global = true)]
+    #[serde(skip)]
+    pub config: Option<PathBuf>,
+    /// Folder of the edition crates.
+    #[arg(long, global = true)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editions_dir: Option<PathBuf>,
+    /// Folder of the HTTP cache.
+    #[arg(long, global = true)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_dir: Option<PathBuf>,
+    /// Folder of the puzzle inputs.
+    #[arg(long, global = true)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inputs_dir: Option<PathBuf>,
+    /// Folder where solutions write their debug output.
+    #[arg(long, global = true)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debug_dir: Option<PathBuf>,
 }
 
 impl Config {
-    pub fn from_env() -> Result<Self> {
-        Figment::new()
-            .merge(Env::prefixed("AOC_"))
-            .extract()
-            .map_err(Into::into)
+    /// Load every config source, from the lowest to the highest priority.
+    pub fn load(args: &ConfigArgs) -> Result<Self> {
+        let root = workspace_root();
+        let project_file = args
+            .config
+            .clone()
+            .unwrap_or_else(|| root.join(PROJECT_CONFIG_FILE));
+
+        let mut figment = Figment::new();
+        if let Some(user_file) = user_config_path() {
+            figment = figment.merge(Toml::file(user_file));
+        }
+
+        let config = Self::extract(
+            figment
+                .merge(Toml::file(project_file))
+                .merge(Env::prefixed("AOC_"))
+                .merge(Serialized::defaults(args)),
+        )?;
+
+        Ok(config.with_root(&root))
+    }
+
+    fn extract(figment: Figment) -> Result<Self> {
+        figment.extract().map_err(Into::into)
+    }
+
+    /// Resolve relative paths against the workspace root.
+    fn with_root(mut self, root: &Path) -> Self {
+        for path in [
+            &mut self.session_token_file,
+            &mut self.editions_dir,
+            &mut self.cache_dir,
+            &mut self.inputs_dir,
+            &mut self.debug_dir,
+            &mut self.record_dir,
+            &mut self.replay_dir,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if path.is_relative() {
+                *path = root.join(&*path);
+            }
+        }
+
+        self
+    }
+
+    /// Session token from a flag, the config or the token file.
+    pub fn session_token(&self, flag: Option<String>) -> Result<String> {
+        if let Some(token) = flag.or_else(|| self.session_token.clone()) {
+            return Ok(token);
+        }
+
+        match &self.session_token_file {
+            Some(path) => Ok(std::fs::read_to_string(path)
+                .map_err(|e| eyre!("Could not read session token file {path:?}: {e}"))?
+                .trim()
+                .to_owned()),
+            None => Err(eyre!(
+                "Missing session token: use --session-token, AOC_SESSION_TOKEN, or `session_token` / `session_token_file` in {PROJECT_CONFIG_FILE}."
+            )),
+        }
+    }
+
+    pub fn editions_dir(&self) -> PathBuf {
+        self.editions_dir
+            .clone()
+            .unwrap_or_else(|| workspace_root().join(EDITIONS_DIR_NAME))
+    }
+
+    pub fn inputs_dir(&self) -> PathBuf {
+        self.inputs_dir.clone().unwrap_or_else(default_inputs_dir)
+    }
+
+    pub fn cache(&self) -> Cache {
+        match &self.cache_dir {
+            Some(dir) => Cache::new(dir),
+            None => Cache::from_env(),
+        }
+    }
+
+    /// Expose the debug folder to solutions, which read it from `AOC_DEBUG_DIR`.
+    pub fn export_debug_dir(&self) {
+        if let Some(dir) = &self.debug_dir {
+            std::env::set_var(DEBUG_DIR_ENV, dir);
+        }
+    }
+}
+
+/// User config file, in `$XDG_CONFIG_HOME/aoc-sx` or `~/.config/aoc-sx`.
+fn user_config_path() -> Option<PathBuf> {
+    std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("aoc-sx").join("config.toml"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use figment::{
+        providers::{Format, Serialized, Toml},
+        Figment,
+    };
+
+    use super::{Config, ConfigArgs};
+
+    #[test]
+    fn layers_and_paths() {
+        let user = Toml::string("session_token = \"user\"\ncache_dir = \"/tmp/cache\"");
+        let project = Toml::string("session_token = \"project\"\ninputs_dir = \"my-inputs\"");
+        let flags = ConfigArgs {
+            inputs_dir: Some(PathBuf::from("/data/inputs")),
+            ..Default::default()
+        };
+
+        let config = Config::extract(
+            Figment::new()
+                .merge(user)
+                .merge(project)
+                .merge(Serialized::defaults(&flags)),
+        )
+        .unwrap()
+        .with_root(Path::new("/repo"));
+
+        assert_eq!(config.session_token(None).unwrap(), "project");
+        assert_eq!(config.session_token(Some("flag".into())).unwrap(), "flag");
+        assert_eq!(config.cache_dir, Some(PathBuf::from("/tmp/cache")));
+        assert_eq!(config.inputs_dir(), PathBuf::from("/data/inputs"));
+
+        let config = Config::extract(Figment::new().merge(Toml::string("debug_dir = \"debug\"")))
+            .unwrap()
+            .with_root(Path::new("/repo"));
+        assert_eq!(config.debug_dir, Some(PathBuf::from("/repo/debug")));
+        assert!(config.session_token(None).is_err());
     }
 }
@@ -0,0 +1,78 @@
+//! Private leaderboard reports.
+
+use std::time::Duration;
+
+use aoc_sx_core::exercise::{ExerciseDay, ExercisePart, ExerciseYear};
+use aoc_sx_webclient::leaderboard::PrivateLeaderboard;
+
+use crate::runner::print_table;
+
+/// Print the standings of a leaderboard.
+pub fn print_standings(leaderboard: &PrivateLeaderboard) {
+    let header = ["Rank", "Name", "Score", "Stars"]
+        .map(String::from)
+        .to_vec();
+    let mut rows = vec![header];
+
+    for (idx, member) in leaderboard.standings().into_iter().enumerate() {
+        rows.push(vec![
+            (idx + 1).to_string(),
+            member.display_name(),
+            member.local_score.to_string(),
+            member.stars.to_string(),
+        ]);
+    }
+
+    print_table(&rows);
+}
+
+/// Print completion times of a day, from its unlock, with the delta between both parts.
+pub fn print_day_times(leaderboard: &PrivateLeaderboard, year: ExerciseYear, day: ExerciseDay) {
+    let header = ["Name", "Part 1", "Part 2", "Delta"]
+        .map(String::from)
+        .to_vec();
+    let mut rows = vec![header];
+
+    let mut members = leaderboard
+        .members
+        .values()
+        .filter(|m| m.star(day, ExercisePart::First).is_some())
+        .collect::<Vec<_>>();
+    // Fastest second star first, then fastest first star
+    members.sort_by_key(|m| {
+        (
+            m.star(day, ExercisePart::Second)
+                .map_or(u64::MAX, |s| s.get_star_ts),
+            m.star(day, ExercisePart::First)
+                .map_or(u64::MAX, |s| s.get_star_ts),
+        )
+    });
+
+    for member in members {
+        let time = |part| {
+            member
+                .completion_time(year, day, part)
+                .map(format_elapsed)
+                .unwrap_or_default()
+        };
+
+        rows.push(vec![
+            member.display_name(),
+            time(ExercisePart::First),
+            time(ExercisePart::Second),
+            member
+                .part_delta(day)
+                .map(format_elapsed)
+                .unwrap_or_default(),
+        ]);
+    }
+
+    println!("Day {day}");
+    print_table(&rows);
+}
+
+/// Format a duration as `HH:MM:SS`, hours going past 24.
+fn format_elapsed(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
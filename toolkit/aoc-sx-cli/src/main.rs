@@ -1,21 +1,58 @@
+pub mod bench;
 pub mod config;
+pub mod leaderboard;
 pub mod logging;
+pub mod release;
+pub mod runner;
+pub mod solutions;
+pub mod sync;
 
 use dotenvy::dotenv;
-use std::path::PathBuf;
+use std::{
+    io::Write,
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
 
-use aoc_sx_codegen::{ModuleGenerator, ModuleParameters};
-use aoc_sx_core::exercise::{ExerciseDay, ExercisePart, ExerciseYear};
-use color_eyre::Result;
+use aoc_sx_codegen::{
+    edition::EditionGenerator,
+    progress::{render_editions, replace_editions_section, YearProgress},
+    ModuleGenerator, ModuleParameters,
+};
+use aoc_sx_core::{
+    answers::AnswerStore,
+    calendar::Calendar,
+    exercise::{Answer, ExerciseDay, ExercisePart, ExerciseYear},
+    input::{input_path, workspace_root, Error as InputError, InputSource},
+};
+use color_eyre::{eyre::eyre, Result};
 
-use aoc_sx_webclient::Client;
-use clap::Parser;
-use config::Config;
+use aoc_sx_webclient::{
+    submissions::SubmissionHistory,
+    throttle::Throttle,
+    transport::{Recorder, Replay, UreqTransport},
+    Client, Error as WebError, PuzzleAnswer,
+};
+use bench::{
+    bench_solution, compare, new_record, print_comparison_table, BenchHistory, BenchOptions,
+};
+use clap::{Parser, ValueEnum};
+use config::{Config, ConfigArgs};
+use leaderboard::{print_day_times, print_standings};
+use release::{await_and_scaffold, format_countdown, target_day, Retry};
+use runner::{print_day_run, print_summary_table, run_solution};
+use sync::{print_sync_summary, Syncer};
+use url::Url;
 
 /// AoC Sx Toolkit
 #[derive(Debug, Parser)]
 #[command(author, version, about)]
 struct Args {
+    /// Only use cached pages and inputs.
+    #[arg(long, global = true)]
+    offline: bool,
+    #[command(flatten)]
+    config: ConfigArgs,
     /// Command to execute
     #[clap(subcommand)]
     command: Subcommand,
@@ -25,10 +62,26 @@ struct Args {
 enum Subcommand {
     /// Generate day module
     Codegen(GenerateDayModule),
+    /// Wait for a day to unlock, then generate its module
+    Await(AwaitDay),
+    /// Create an edition crate
+    NewEdition(NewEdition),
     /// Fetch missing inputs
     FetchMissingInputs(FetchMissingInputs),
+    /// Fetch inputs and pages of every unlocked day
+    Sync(SyncDays),
     /// Send answer
     SendAnswer(SendAnswer),
+    /// Run solutions
+    Run(RunSolution),
+    /// Benchmark solutions
+    Bench(BenchSolutions),
+    /// Show a private leaderboard
+    Leaderboard(ShowLeaderboard),
+    /// Update the editions progress in the README
+    Progress(UpdateProgress),
+    /// Check the session token
+    Whoami(ShowAccount),
 }
 
 #[derive(Debug, Parser)]
@@ -42,6 +95,29 @@ struct GenerateDayModule {
     /// Day
     #[arg(short, long)]
     pub day: ExerciseDay,
+    /// Only refresh the docs of an existing module, e.g. once part 2 is unlocked.
+    #[arg(long)]
+    pub update: bool,
+}
+
+#[derive(Debug, Parser)]
+struct AwaitDay {
+    /// Session token.
+    #[arg(long)]
+    pub session_token: Option<String>,
+    /// Year (the next day to unlock if not set).
+    #[arg(short, long, requires = "day")]
+    pub year: Option<ExerciseYear>,
+    /// Day.
+    #[arg(short, long, requires = "year")]
+    pub day: Option<ExerciseDay>,
+}
+
+#[derive(Debug, Parser)]
+struct NewEdition {
+    /// Year.
+    #[arg(short, long)]
+    pub year: ExerciseYear,
 }
 
 #[derive(Debug, Parser)]
@@ -59,7 +135,112 @@ struct SendAnswer {
     #[arg(short, long)]
     pub part: ExercisePart,
     /// Answer
-    pub answer: String,
+    pub answer: Answer,
+    /// Send the answer even if previous submissions rule it out.
+    #[arg(long)]
+    pub force: bool,
+}
+
+#[derive(Debug, Parser)]
+struct RunSolution {
+    /// Year.
+    #[arg(short, long)]
+    pub year: ExerciseYear,
+    /// Day.
+    #[arg(short, long, required_unless_present = "all")]
+    pub day: Option<ExerciseDay>,
+    /// Part (both parts if not set).
+    #[arg(short, long)]
+    pub part: Option<ExercisePart>,
+    /// Input file ('-' for standard input).
+    #[arg(long, conflicts_with = "all")]
+    pub input: Option<PathBuf>,
+    /// Run every registered day of the year.
+    #[arg(long, conflicts_with = "day")]
+    pub all: bool,
+}
+
+#[derive(Debug, Parser)]
+struct BenchSolutions {
+    /// Year (every year if not set).
+    #[arg(short, long)]
+    pub year: Option<ExerciseYear>,
+    /// Day (every day if not set).
+    #[arg(short, long, requires = "year")]
+    pub day: Option<ExerciseDay>,
+    /// Warm-up iterations.
+    #[arg(long, default_value_t = 3)]
+    pub warmup: usize,
+    /// Measured iterations.
+    #[arg(short, long, default_value_t = 10)]
+    pub iterations: usize,
+    /// Regression threshold, in percent.
+    #[arg(long, default_value_t = 10.0)]
+    pub threshold: f64,
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, Parser)]
+struct ShowLeaderboard {
+    /// Session token.
+    #[arg(long)]
+    pub session_token: Option<String>,
+    /// Year.
+    #[arg(short, long)]
+    pub year: ExerciseYear,
+    /// Leaderboard ID (defaults to `AOC_LEADERBOARD_ID`).
+    #[arg(long)]
+    pub id: Option<u64>,
+    /// Day (every day with stars if not set).
+    #[arg(short, long)]
+    pub day: Option<ExerciseDay>,
+}
+
+#[derive(Debug, Parser)]
+struct UpdateProgress {
+    /// Session token, to merge stars from the event pages.
+    #[arg(long)]
+    pub session_token: Option<String>,
+    /// Merge stars from the event pages.
+    #[arg(long)]
+    pub stars: bool,
+    /// Add a per-day grid of stars.
+    #[arg(long)]
+    pub grid: bool,
+    /// Print the section instead of writing the README.
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Parser)]
+struct ShowAccount {
+    /// Session token.
+    #[arg(long)]
+    pub session_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Table,
+    Json,
+}
+
+#[derive(Debug, Parser)]
+struct SyncDays {
+    /// Session token.
+    #[arg(long)]
+    pub session_token: Option<String>,
+    /// Years (every edition if not set).
+    #[arg(short, long)]
+    pub year: Vec<ExerciseYear>,
+    /// Also create missing day modules.
+    #[arg(long)]
+    pub scaffold: bool,
+    /// Only show what would be fetched or created.
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 #[derive(Debug, Parser)]
@@ -79,24 +260,83 @@ fn main() -> Result<()> {
     crate::logging::Logging::setup()?;
 
     let args = Args::parse();
-    let config = Config::from_env()?;
+    let config = Config::load(&args.config)?;
+    config.export_debug_dir();
+    let calendar = Calendar::new();
 
     match args.command {
         Subcommand::Codegen(GenerateDayModule {
             session_token,
             year,
             day,
+            update,
         }) => {
-            let token = session_token
-                .or(config.session_token)
-                .expect("Missing session token.");
-
-            let path = PathBuf::from(".")
-                .join("editions")
-                .join(format!("aoc-{year}"))
-                .join("src");
-            let generator = ModuleGenerator::new(token);
-            generator.generate_module(path, ModuleParameters { year, day })?;
+            calendar.check_day(year, day)?;
+
+            let token = config.session_token(session_token)?;
+
+            let editions =
+                EditionGenerator::from_workspace().with_editions_dir(config.editions_dir());
+            print_changed_files(&editions.generate_edition(year)?);
+
+            let path = editions.src_dir(year);
+            let generator = ModuleGenerator::from_client(new_client(token, args.offline, &config)?)
+                .with_inputs_dir(config.inputs_dir());
+            if update {
+                let diff = generator.update_module_docs(path, ModuleParameters { year, day })?;
+                print!("{diff}");
+            } else {
+                generator.generate_module(path, ModuleParameters { year, day })?;
+            }
+        }
+
+        Subcommand::Await(AwaitDay {
+            session_token,
+            year,
+            day,
+        }) => {
+            if args.offline {
+                return Err(eyre!("Cannot wait for a day to unlock in offline mode."));
+            }
+
+            let (year, day) = target_day(&calendar, year, day)
+                .ok_or_else(|| eyre!("Every day of this year's event is already unlocked."))?;
+            calendar.check_year_exists(year)?;
+            calendar.check_day_exists(year, day)?;
+
+            let token = config.session_token(session_token)?;
+            let editions =
+                EditionGenerator::from_workspace().with_editions_dir(config.editions_dir());
+            print_changed_files(&editions.generate_edition(year)?);
+
+            let generator = ModuleGenerator::from_client(new_client(token, args.offline, &config)?)
+                .with_inputs_dir(config.inputs_dir());
+            await_and_scaffold(
+                &calendar,
+                &generator,
+                &editions.src_dir(year),
+                ModuleParameters { year, day },
+                Retry::default(),
+                |remaining| {
+                    print!(
+                        "\rDay {day} of {year} unlocks in {} ",
+                        format_countdown(remaining)
+                    );
+                    let _ = std::io::stdout().flush();
+                },
+            )?;
+        }
+
+        Subcommand::NewEdition(NewEdition { year }) => {
+            calendar.check_year_exists(year)?;
+
+            let changed = EditionGenerator::from_workspace()
+                .with_editions_dir(config.editions_dir())
+                .generate_edition(year)?;
+            if changed.is_empty() {
+                println!("Edition {year} already exists.");
+            }
+            print_changed_files(&changed);
         }
 
         Subcommand::SendAnswer(SendAnswer {
@@ -105,23 +345,324 @@ fn main() -> Result<()> {
             day,
             part,
             answer,
+            force,
         }) => {
-            let token = session_token
-                .or(config.session_token)
-                .expect("Missing session token");
-            let client = Client::new(token);
+            calendar.check_part(year, day, part)?;
+
+            let mut history = SubmissionHistory::from_env()?;
+            if !force {
+                if let Err(refusal) = history.check(year, day, part, &answer, SystemTime::now()) {
+                    return Err(eyre!(
+                        "Not sending answer: {refusal} Use --force to send it anyway."
+                    ));
+                }
+            }
+
+            let token = config.session_token(session_token)?;
+            let client = new_client(token, args.offline, &config)?;
             let response = client.send_answer(&answer, year, day, part)?;
             println!("{:?}", response);
+
+            history.record(
+                year,
+                day,
+                part,
+                &answer,
+                response.clone(),
+                SystemTime::now(),
+            );
+            history.save()?;
+
+            if let PuzzleAnswer::Success(_) = response {
+                // Record the accepted answer for this input
+                match InputSource::Directory(config.inputs_dir()).load(year, day) {
+                    Ok(input) => {
+                        let mut store = AnswerStore::from_env()?;
+                        store.record(year, day, part, &input, &answer);
+                        store.save()?;
+                        println!("Answer recorded.");
+                    }
+                    Err(e) => println!("Answer not recorded: {e}"),
+                }
+            }
+        }
+
+        Subcommand::Run(RunSolution {
+            year,
+            day,
+            part,
+            input,
+            all,
+        }) => {
+            let registry = crate::solutions::registry();
+            let input_source = match input {
+                Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+                Some(path) => InputSource::File(path),
+                None => InputSource::Directory(config.inputs_dir()),
+            };
+
+            if all {
+                calendar.check_year(year)?;
+
+                let runs = registry
+                    .solutions_for_year(year)
+                    .map(|solution| {
+                        let run = input_source
+                            .load(solution.year(), solution.day())
+                            .map(|input| run_solution(solution, &input, part))
+                            .map_err(|e| match e {
+                                InputError::MissingInput(_) => "missing input".to_string(),
+                                e => e.to_string(),
+                            });
+                        (solution, run)
+                    })
+                    .collect::<Vec<_>>();
+
+                println!("{year}\n");
+                print_summary_table(&runs);
+            } else {
+                let day = day.expect("Day is required without --all.");
+                match part {
+                    Some(part) => calendar.check_part(year, day, part)?,
+                    None => calendar.check_day(year, day)?,
+                }
+
+                let solution = registry
+                    .get(year, day)
+                    .ok_or_else(|| eyre!("No solution registered for {year} day {day}."))?;
+                let input = input_source.load(year, day)?;
+                let run = run_solution(solution, &input, part);
+                print_day_run(solution, &run);
+            }
+        }
+
+        Subcommand::Bench(BenchSolutions {
+            year,
+            day,
+            warmup,
+            iterations,
+            threshold,
+            format,
+        }) => {
+            if let Some(year) = year {
+                calendar.check_year(year)?;
+                if let Some(day) = day {
+                    calendar.check_day(year, day)?;
+                }
+            }
+
+            let registry = crate::solutions::registry();
+            let input_source = InputSource::Directory(config.inputs_dir());
+            let options = BenchOptions { warmup, iterations };
+
+            let mut results = vec![];
+            let mut skipped = 0;
+            for solution in registry.solutions() {
+                if year.is_some_and(|y| y != solution.year())
+                    || day.is_some_and(|d| d != solution.day())
+                {
+                    continue;
+                }
+
+                match input_source.load(solution.year(), solution.day()) {
+                    Ok(input) => results.push(bench_solution(solution, &input, options)),
+                    Err(InputError::MissingInput(_)) => skipped += 1,
+                    Err(e) => return Err(e.into()),
+                }
+            }
+
+            let history = BenchHistory::from_workspace();
+            let baseline = history.baseline()?;
+            let comparisons = compare(&results, &baseline, threshold);
+
+            match format {
+                OutputFormat::Table => print_comparison_table(&comparisons),
+                OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&comparisons)?),
+            }
+
+            if skipped > 0 {
+                eprintln!("Skipped {skipped} day(s) without input.");
+            }
+
+            if !results.is_empty() {
+                history.append(&new_record(results, options))?;
+            }
+        }
+
+        Subcommand::Leaderboard(ShowLeaderboard {
+            session_token,
+            year,
+            id,
+            day,
+        }) => {
+            calendar.check_year(year)?;
+            if let Some(day) = day {
+                calendar.check_day(year, day)?;
+            }
+
+            let id = id
+                .or(config.leaderboard_id)
+                .ok_or_else(|| eyre!("Missing leaderboard ID"))?;
+            let token = config.session_token(session_token)?;
+            let client = new_client(token, args.offline, &config)?;
+            let leaderboard = client.fetch_private_leaderboard(year, id)?;
+
+            print_standings(&leaderboard);
+
+            let days = match day {
+                Some(day) => vec![day],
+                None => leaderboard
+                    .days()
+                    .into_iter()
+                    .map(ExerciseDay::try_from)
+                    .collect::<Result<_, _>>()?,
+            };
+            for day in days {
+                println!();
+                print_day_times(&leaderboard, year, day);
+            }
+        }
+
+        Subcommand::Progress(UpdateProgress {
+            session_token,
+            stars,
+            grid,
+            dry_run,
+        }) => {
+            let answers = AnswerStore::from_env()?;
+            let client = match stars {
+                true => Some(new_client(
+                    config.session_token(session_token)?,
+                    args.offline,
+                    &config,
+                )?),
+                false => None,
+            };
+
+            let mut progress = vec![];
+            for year in EditionGenerator::from_workspace()
+                .with_editions_dir(config.editions_dir())
+                .edition_years()?
+            {
+                let year = ExerciseYear::try_from(year)?;
+                let mut year_progress = YearProgress::new(year, &answers);
+                if let Some(client) = &client {
+                    if calendar.check_year(year).is_ok() {
+                        year_progress.merge_stars(&client.fetch_event_stars(year)?);
+                    }
+                }
+                progress.push(year_progress);
+            }
+
+            let section = render_editions(&progress, grid);
+            if dry_run {
+                print!("{section}");
+            } else {
+                let readme_path = workspace_root().join("README.md");
+                let readme = std::fs::read_to_string(&readme_path)?;
+                let updated = replace_editions_section(&readme, &section)?;
+                if updated != readme {
+                    std::fs::write(&readme_path, updated)?;
+                    print_changed_files(&[readme_path]);
+                }
+            }
+        }
+
+        Subcommand::Whoami(ShowAccount { session_token }) => {
+            let from_file = session_token.is_none() && config.session_token.is_none();
+            let token = config.session_token(session_token)?;
+            let client = new_client(token, args.offline, &config)?;
+
+            match client.fetch_account() {
+                Ok(account) => {
+                    match account.stars {
+                        Some(stars) => println!("Logged in as {} ({stars}*).", account.name),
+                        None => println!("Logged in as {}.", account.name),
+                    }
+                    print_token_age(&config, from_file);
+                }
+                Err(WebError::Unauthenticated) => {
+                    print_token_age(&config, from_file);
+                    return Err(eyre!(
+                        "The session token is invalid or expired: copy the `session` cookie from a logged-in browser."
+                    ));
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Subcommand::Sync(SyncDays {
+            session_token,
+            year,
+            scaffold,
+            dry_run,
+        }) => {
+            let editions =
+                EditionGenerator::from_workspace().with_editions_dir(config.editions_dir());
+            let years = match year.is_empty() {
+                true => editions
+                    .edition_years()?
+                    .into_iter()
+                    .map(ExerciseYear::try_from)
+                    .collect::<Result<Vec<_>, _>>()?,
+                false => year,
+            };
+
+            // A dry run only looks at the cache, without a token
+            let client = match dry_run {
+                true => new_client(String::new(), true, &config)?,
+                false => new_client(config.session_token(session_token)?, args.offline, &config)?,
+            };
+            let generator =
+                ModuleGenerator::from_client(client).with_inputs_dir(config.inputs_dir());
+
+            let mut failed = 0;
+            for year in years {
+                calendar.check_year(year)?;
+
+                let mut syncer =
+                    Syncer::new(generator.client(), config.inputs_dir()).with_dry_run(dry_run);
+                if scaffold {
+                    if !dry_run {
+                        print_changed_files(&editions.generate_edition(year)?);
+                    }
+                    syncer = syncer.with_scaffold(&generator, editions.src_dir(year));
+                }
+
+                let mut days = vec![];
+                let mut fatal = None;
+                for day in calendar.unlocked_days(year) {
+                    match syncer.sync_day(year, day) {
+                        Ok(synced) => days.push(synced),
+                        Err(e) => {
+                            fatal = Some(e);
+                            break;
+                        }
+                    }
+                }
+                failed += days.iter().filter(|d| d.is_failed()).count();
+
+                println!("{year}\n");
+                print_sync_summary(&days);
+                println!();
+
+                if let Some(e) = fatal {
+                    return Err(e.into());
+                }
+            }
+
+            if failed > 0 {
+                return Err(eyre!("{failed} day(s) could not be synced."));
+            }
         }
 
         Subcommand::FetchMissingInputs(FetchMissingInputs { session_token }) => {
-            let token = session_token
-                .or(config.session_token)
-                .expect("Missing session token");
-            let client = Client::new(token);
+            let token = config.session_token(session_token)?;
+            let client = new_client(token, args.offline, &config)?;
+            let inputs_dir = config.inputs_dir();
 
             // Scan for missing inputs in editions
-            let editions_path = PathBuf::from(".").join("editions");
+            let editions_path = config.editions_dir();
 
             for year_folder in std::fs::read_dir(editions_path)? {
                 let year_folder = year_folder?;
@@ -130,12 +671,13 @@ fn main() -> Result<()> {
                 }
 
                 let folder_name = year_folder.file_name().to_string_lossy().to_string();
-                let folder_name_split = folder_name.split('-').collect::<Vec<_>>();
-                if folder_name_split.len() != 2 {
-                    panic!("Edition folder should match 'aoc-[year]'.");
-                }
-
-                let year = ExerciseYear::try_from(folder_name_split[1].parse::<u16>()?)?;
+                let year = folder_name
+                    .strip_prefix("aoc-")
+                    .and_then(|year| year.parse::<u16>().ok())
+                    .ok_or_else(|| {
+                        eyre!("Edition folder should match 'aoc-[year]' (input: {folder_name})")
+                    })?;
+                let year = ExerciseYear::try_from(year)?;
 
                 for day_folder in std::fs::read_dir(year_folder.path().join("src"))? {
                     let day_folder = day_folder?;
@@ -144,18 +686,25 @@ fn main() -> Result<()> {
                     }
 
                     let folder_name = day_folder.file_name().to_string_lossy().to_string();
-                    let (folder_name_title, folder_name_value) = folder_name.split_at(3);
-                    if folder_name_title != "day" {
-                        panic!("Day folder should match 'day[day]' (input: {folder_name_title})");
+                    let day = folder_name
+                        .strip_prefix("day")
+                        .and_then(|day| day.parse::<u8>().ok())
+                        .ok_or_else(|| {
+                            eyre!("Day folder should match 'day[day]' (input: {folder_name})")
+                        })?;
+                    let day = ExerciseDay::try_from(day)?;
+                    if !calendar.is_unlocked(year, day) {
+                        continue;
                     }
 
-                    let day = ExerciseDay::try_from(folder_name_value.parse::<u8>()?)?;
-
-                    // Check for input.txt
-                    let input_txt_path = day_folder.path().join("input.txt");
+                    // Check for the input file
+                    let input_txt_path = input_path(&inputs_dir, year, day);
                     if !input_txt_path.exists() {
                         let puzzle_input = client.fetch_input_page(year, day)?;
                         println!("Creating {input_txt_path:?} ...");
+                        if let Some(parent) = input_txt_path.parent() {
+                            std::fs::create_dir_all(parent)?;
+                        }
                         std::fs::write(&input_txt_path, puzzle_input.as_str())?;
                     }
                 }
@@ -165,3 +714,54 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+fn new_client(session_token: String, offline: bool, config: &Config) -> Result<Client> {
+    let cache = config.cache().with_offline(offline);
+    let throttle = Throttle::default().with_state_file(cache.dir().join("throttle.state"));
+
+    let mut client = Client::new(session_token.clone())
+        .with_throttle(throttle)
+        .with_cache(cache);
+
+    if let Some(dir) = &config.replay_dir {
+        client = client
+            .with_transport(Replay::new(dir))
+            .with_throttle(Throttle::new(Duration::ZERO));
+    } else if let Some(dir) = &config.record_dir {
+        let recorder = Recorder::new(UreqTransport::new(session_token.clone()), dir);
+        client = client.with_transport(recorder.with_secret(&session_token));
+    }
+
+    if let Some(base_url) = &config.base_url {
+        client = client.with_base_url(Url::parse(base_url)?);
+    }
+
+    Ok(match &config.user_agent_contact {
+        Some(contact) => client.with_user_agent_contact(contact),
+        None => client,
+    })
+}
+
+/// Session tokens usually last about a month: show the age of the token file.
+fn print_token_age(config: &Config, from_file: bool) {
+    let Some(path) = config.session_token_file.as_ref().filter(|_| from_file) else {
+        return;
+    };
+
+    let age = std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    if let Some(age) = age {
+        println!(
+            "Token file {path:?} was updated {} day(s) ago, sessions usually last about a month.",
+            age.as_secs() / 86400
+        );
+    }
+}
+
+fn print_changed_files(files: &[PathBuf]) {
+    for file in files {
+        println!("Updated {file:?}");
+    }
+}
@@ -0,0 +1,203 @@
+//! Release time: wait for a day to unlock, then scaffold it.
+
+use std::{path::Path, time::Duration};
+
+use aoc_sx_codegen::{ModuleGenerator, ModuleParameters};
+use aoc_sx_core::{
+    calendar::{Calendar, Clock},
+    exercise::{ExerciseDay, ExerciseYear},
+};
+use aoc_sx_webclient::Error as WebError;
+use color_eyre::Result;
+
+/// Retries of a day which is not live yet, the server lagging a bit behind the unlock.
+#[derive(Debug, Clone, Copy)]
+pub struct Retry {
+    pub attempts: u32,
+    /// Delay before the first retry, growing with each attempt.
+    pub delay: Duration,
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Self {
+            attempts: 5,
+            delay: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Retry {
+    /// Run a request until it succeeds or fails with anything else than a 404.
+    pub fn run<C: Clock, T>(
+        &self,
+        calendar: &Calendar<C>,
+        mut request: impl FnMut() -> Result<T, WebError>,
+    ) -> Result<T, WebError> {
+        let mut attempt = 1;
+        loop {
+            match request() {
+                Err(WebError::HttpStatus { status: 404, .. }) if attempt < self.attempts => {
+                    let delay = self.delay * attempt;
+                    println!("Not live yet, retrying in {}s ...", delay.as_secs());
+                    calendar.sleep(delay);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Countdown, e.g. `1d 02:03:04`.
+pub fn format_countdown(remaining: Duration) -> String {
+    // Round up, to never show 00:00:00 before the unlock
+    let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600 % 24,
+        secs / 60 % 60,
+        secs % 60
+    );
+
+    match secs / 86400 {
+        0 => time,
+        days => format!("{days}d {time}"),
+    }
+}
+
+/// Wait for a day to unlock, fetch its page and input as soon as they are live, then generate its module.
+pub fn await_and_scaffold<C: Clock>(
+    calendar: &Calendar<C>,
+    generator: &ModuleGenerator,
+    src_dir: &Path,
+    parameters: ModuleParameters,
+    retry: Retry,
+    tick: impl FnMut(Duration),
+) -> Result<()> {
+    let ModuleParameters { year, day } = parameters;
+    calendar.wait_for_unlock(year, day, tick);
+
+    // Cached by the client, then reused by the generator
+    let client = generator.client();
+    retry.run(calendar, || client.fetch_exercise_page(year, day))?;
+    retry.run(calendar, || client.fetch_input_page(year, day))?;
+
+    generator.generate_module(src_dir, parameters)?;
+    Ok(())
+}
+
+/// Day to wait for, from flags or the next day to unlock.
+pub fn target_day<C: Clock>(
+    calendar: &Calendar<C>,
+    year: Option<ExerciseYear>,
+    day: Option<ExerciseDay>,
+) -> Option<(ExerciseYear, ExerciseDay)> {
+    match (year, day) {
+        (Some(year), Some(day)) => Some((year, day)),
+        _ => calendar.next_day(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicU32, Ordering},
+        time::Duration,
+    };
+
+    use aoc_sx_codegen::{ModuleGenerator, ModuleParameters};
+    use aoc_sx_core::{
+        calendar::{unlock_time, Calendar, SimulatedClock},
+        exercise::{ExerciseDay, ExerciseYear},
+    };
+    use aoc_sx_mock::{fixtures_dir, Fixtures, MockServer};
+    use aoc_sx_webclient::{
+        cache::Cache,
+        throttle::Throttle,
+        transport::{Request, Response, Transport, UreqTransport},
+        Client, Error as WebError,
+    };
+    use url::Url;
+
+    use super::{await_and_scaffold, format_countdown, target_day, Retry};
+
+    const SESSION: &str = "mock-session";
+
+    /// Answers 404 to the first requests, as the server does right before a day is live.
+    #[derive(Debug)]
+    struct NotLiveYet {
+        inner: UreqTransport,
+        remaining: AtomicU32,
+    }
+
+    impl Transport for NotLiveYet {
+        fn send(&self, request: &Request) -> Result<Response, WebError> {
+            match self
+                .remaining
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |r| r.checked_sub(1))
+            {
+                Ok(_) => Ok(Response {
+                    status: 404,
+                    body: "404 Not Found".into(),
+                }),
+                Err(_) => self.inner.send(request),
+            }
+        }
+    }
+
+    #[test]
+    fn await_unlock_then_scaffold() {
+        let server = MockServer::start(Fixtures::new(fixtures_dir(), SESSION)).unwrap();
+        let dir = std::env::temp_dir().join(format!("aoc-sx-release-{}", std::process::id()));
+        let year = ExerciseYear::try_from(2015).unwrap();
+        let day = ExerciseDay::try_from(1).unwrap();
+
+        let unlock = unlock_time(year, day);
+        let calendar = Calendar::with_clock(SimulatedClock::new(unlock - Duration::from_secs(3)));
+        assert_eq!(target_day(&calendar, None, None), Some((year, day)));
+
+        let client = Client::new(SESSION.into())
+            .with_transport(NotLiveYet {
+                inner: UreqTransport::new(SESSION.into()),
+                remaining: AtomicU32::new(2),
+            })
+            .with_base_url(Url::parse(server.url()).unwrap())
+            .with_throttle(Throttle::new(Duration::ZERO))
+            .with_cache(Cache::new(dir.join("cache")));
+        let generator = ModuleGenerator::from_client(client).with_inputs_dir(dir.join("inputs"));
+
+        let mut countdown = vec![];
+        await_and_scaffold(
+            &calendar,
+            &generator,
+            &dir.join("src"),
+            ModuleParameters { year, day },
+            Retry::default(),
+            |remaining| countdown.push(format_countdown(remaining)),
+        )
+        .unwrap();
+
+        assert_eq!(countdown, ["00:00:03", "00:00:02", "00:00:01"]);
+        // Retried after 5s, then 10s
+        assert_eq!(calendar.now(), unlock + Duration::from_secs(15));
+        assert!(dir.join("src/day01/mod.rs").exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.join("inputs/2015/day01.txt")).unwrap(),
+            "3 1 4 1 5\n"
+        );
+        let paths = server
+            .requests()
+            .into_iter()
+            .map(|r| r.path)
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["/2015/day/1", "/2015/day/1/input"]);
+
+        assert_eq!(
+            format_countdown(Duration::from_millis(90061500)),
+            "1d 01:01:02"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
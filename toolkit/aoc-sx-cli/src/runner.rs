@@ -0,0 +1,229 @@
+//! Solution runner.
+
+use std::time::{Duration, Instant};
+
+use aoc_sx_core::{
+    exercise::{Answer, ExercisePart},
+    solution::RegisteredSolution,
+};
+
+const PARTS: [ExercisePart; 2] = [ExercisePart::First, ExercisePart::Second];
+
+/// Result of a solution run.
+#[derive(Debug)]
+pub struct DayRun {
+    pub parts: Vec<PartRun>,
+}
+
+/// Result of a part run.
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: ExercisePart,
+    pub answer: Answer,
+    pub duration: Duration,
+}
+
+impl DayRun {
+    pub fn total_duration(&self) -> Duration {
+        self.parts.iter().map(|p| p.duration).sum()
+    }
+
+    pub fn part(&self, part: ExercisePart) -> Option<&PartRun> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+}
+
+/// Solve the selected part of an input, or every part if none is selected.
+pub fn run_solution(
+    solution: &dyn RegisteredSolution,
+    input: &str,
+    part: Option<ExercisePart>,
+) -> DayRun {
+    let parsed = solution.parse(input);
+
+    let parts = PARTS
+        .into_iter()
+        .filter(|p| part.map_or(true, |part| part == *p))
+        .filter_map(|part| {
+            let start = Instant::now();
+            let answer = parsed.solve(part)?;
+            Some(PartRun {
+                part,
+                answer,
+                duration: start.elapsed(),
+            })
+        })
+        .collect();
+
+    DayRun { parts }
+}
+
+/// Print a single day run.
+pub fn print_day_run(solution: &dyn RegisteredSolution, run: &DayRun) {
+    println!("{} day {}", solution.year(), solution.day());
+
+    for part in &run.parts {
+        println!(
+            "  Part {}: {} ({})",
+            part.part.as_u8(),
+            part.answer,
+            format_duration(part.duration)
+        );
+    }
+}
+
+/// Print a summary table of multiple day runs.
+pub fn print_summary_table(runs: &[(&dyn RegisteredSolution, Result<DayRun, String>)]) {
+    let total = runs
+        .iter()
+        .filter_map(|(_, run)| run.as_ref().ok())
+        .map(DayRun::total_duration)
+        .sum();
+
+    print_table(&summary_rows(runs));
+    println!("\nTotal: {}", format_duration(total));
+}
+
+/// Summary rows of multiple day runs, the first row being the header.
+pub fn summary_rows(
+    runs: &[(&dyn RegisteredSolution, Result<DayRun, String>)],
+) -> Vec<Vec<String>> {
+    let header = ["Day", "Part 1", "Time", "Part 2", "Time"]
+        .map(String::from)
+        .to_vec();
+    let mut rows = vec![header];
+
+    for (solution, run) in runs {
+        let mut row = vec![solution.day().to_string()];
+        match run {
+            Ok(run) => {
+                for part in PARTS {
+                    match run.part(part) {
+                        Some(p) => {
+                            row.push(p.answer.to_string());
+                            row.push(format_duration(p.duration));
+                        }
+                        None => row.extend(["-".into(), "-".into()]),
+                    }
+                }
+            }
+            Err(e) => {
+                row.push(e.clone());
+                row.extend(std::iter::repeat(String::new()).take(3));
+            }
+        }
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Print rows as an aligned table, the first row being the header.
+pub fn print_table(rows: &[Vec<String>]) {
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let widths = (0..columns)
+        .map(|c| {
+            rows.iter()
+                .filter_map(|r| r.get(c))
+                .map(|v| v.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    for (idx, row) in rows.iter().enumerate() {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| format!("{value:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        println!("{}", line.trim_end());
+
+        if idx == 0 {
+            let separator = widths
+                .iter()
+                .map(|w| "-".repeat(*w))
+                .collect::<Vec<_>>()
+                .join("-|-");
+            println!("{separator}");
+        }
+    }
+}
+
+/// Format a duration with a readable unit.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_sx_core::{
+        exercise::Answer,
+        solution::{RegisteredSolution, Solution},
+    };
+
+    use super::{format_duration, run_solution, summary_rows, DayRun};
+
+    /// Sums numbers, without a second part.
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2020;
+        const DAY: u8 = 1;
+
+        type Input<'a> = Vec<u32>;
+
+        fn parse(input: &str) -> Self::Input<'_> {
+            input
+                .split_whitespace()
+                .map(|n| n.parse().unwrap())
+                .collect()
+        }
+
+        fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {
+            input.iter().sum::<u32>()
+        }
+
+        fn part2(_input: &Self::Input<'_>) -> Option<impl Into<Answer>> {
+            None::<u32>
+        }
+    }
+
+    #[test]
+    fn format_duration_units() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_000)), "1.00µs");
+        assert_eq!(format_duration(Duration::from_nanos(999_000)), "999.00µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(999)), "999.00ms");
+        assert_eq!(format_duration(Duration::from_millis(2_345)), "2.35s");
+    }
+
+    #[test]
+    fn summary_rows_with_failures() {
+        let run = run_solution(&Sum, "1 2 3", None);
+        assert_eq!(run.parts.len(), 1);
+
+        let runs: Vec<(&dyn RegisteredSolution, Result<DayRun, String>)> =
+            vec![(&Sum, Ok(run)), (&Sum, Err("missing input".into()))];
+        let rows = summary_rows(&runs);
+
+        assert_eq!(rows[0], ["Day", "Part 1", "Time", "Part 2", "Time"]);
+        // No second part
+        assert_eq!((rows[1][0].as_str(), rows[1][1].as_str()), ("1", "6"));
+        assert_eq!(rows[1][3..], ["-", "-"]);
+        assert_eq!(rows[2], ["1", "missing input", "", "", ""]);
+    }
+}
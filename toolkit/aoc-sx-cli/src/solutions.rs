@@ -0,0 +1,14 @@
+//! Solutions of every edition.
+
+use aoc_sx_core::solution::Registry;
+
+/// Build the registry of every edition.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    aoc_2020::register(&mut registry);
+    aoc_2021::register(&mut registry);
+    aoc_2022::register(&mut registry);
+    aoc_2023::register(&mut registry);
+    aoc_2024::register(&mut registry);
+    registry
+}
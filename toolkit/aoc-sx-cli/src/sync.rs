@@ -0,0 +1,276 @@
+//! Sync unlocked days: inputs, pages and, optionally, day modules.
+
+use std::path::{Path, PathBuf};
+
+use aoc_sx_codegen::{ModuleGenerator, ModuleParameters};
+use aoc_sx_core::{
+    exercise::{ExerciseDay, ExerciseYear},
+    input::input_path,
+};
+use aoc_sx_webclient::{cache::Resource, Client, Error as WebError};
+
+use crate::runner::print_table;
+
+/// What happened to a resource of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// Already there, nothing to do.
+    Present,
+    /// Fetched or created.
+    Done,
+    /// Would be fetched or created, in a dry run.
+    Planned,
+    Failed(String),
+}
+
+impl Outcome {
+    fn from_result<E: std::fmt::Display>(result: Result<(), E>) -> Self {
+        match result {
+            Ok(()) => Self::Done,
+            Err(e) => Self::Failed(e.to_string()),
+        }
+    }
+
+    fn label(&self, done: &str, planned: &str) -> String {
+        match self {
+            Self::Present => "present".into(),
+            Self::Done => done.into(),
+            Self::Planned => planned.into(),
+            Self::Failed(_) => "failed".into(),
+        }
+    }
+}
+
+/// Sync result of a day.
+#[derive(Debug)]
+pub struct DaySync {
+    pub day: ExerciseDay,
+    pub input: Outcome,
+    pub page: Outcome,
+    /// Only set when scaffolding modules.
+    pub module: Option<Outcome>,
+}
+
+impl DaySync {
+    pub fn is_failed(&self) -> bool {
+        [Some(&self.input), Some(&self.page), self.module.as_ref()]
+            .into_iter()
+            .flatten()
+            .any(|o| matches!(o, Outcome::Failed(_)))
+    }
+}
+
+/// Fetch missing inputs and pages of days, through a (throttled) client.
+#[derive(Debug)]
+pub struct Syncer<'a> {
+    client: &'a Client,
+    inputs_dir: PathBuf,
+    dry_run: bool,
+    scaffold: Option<(&'a ModuleGenerator, PathBuf)>,
+}
+
+impl<'a> Syncer<'a> {
+    pub fn new(client: &'a Client, inputs_dir: PathBuf) -> Self {
+        Self {
+            client,
+            inputs_dir,
+            dry_run: false,
+            scaffold: None,
+        }
+    }
+
+    /// Only report what would be done, without any request.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Also create missing day modules in an edition `src` folder.
+    pub fn with_scaffold(mut self, generator: &'a ModuleGenerator, src_dir: PathBuf) -> Self {
+        self.scaffold = Some((generator, src_dir));
+        self
+    }
+
+    /// Sync a day, collecting failures, except those which would fail every other day.
+    pub fn sync_day(&self, year: ExerciseYear, day: ExerciseDay) -> Result<DaySync, WebError> {
+        let input = self.sync_input(year, day)?;
+        let page = self.sync_page(year, day)?;
+        let module = self
+            .scaffold
+            .as_ref()
+            .map(|(generator, src_dir)| self.sync_module(generator, src_dir, year, day));
+
+        Ok(DaySync {
+            day,
+            input,
+            page,
+            module,
+        })
+    }
+
+    fn sync_input(&self, year: ExerciseYear, day: ExerciseDay) -> Result<Outcome, WebError> {
+        let path = input_path(&self.inputs_dir, year, day);
+        if path.exists() {
+            return Ok(Outcome::Present);
+        } else if self.dry_run {
+            return Ok(Outcome::Planned);
+        }
+
+        check_fatal(self.client.fetch_input_page(year, day).and_then(|input| {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            Ok(std::fs::write(&path, input.as_str())?)
+        }))
+    }
+
+    fn sync_page(&self, year: ExerciseYear, day: ExerciseDay) -> Result<Outcome, WebError> {
+        let cached = self
+            .client
+            .cache()
+            .is_some_and(|c| c.get(year, Resource::Page(day)).is_some());
+        if cached {
+            return Ok(Outcome::Present);
+        } else if self.dry_run {
+            return Ok(Outcome::Planned);
+        }
+
+        check_fatal(self.client.fetch_exercise_page(year, day).map(|_| ()))
+    }
+
+    fn sync_module(
+        &self,
+        generator: &ModuleGenerator,
+        src_dir: &Path,
+        year: ExerciseYear,
+        day: ExerciseDay,
+    ) -> Outcome {
+        let mod_rs = src_dir
+            .join(format!("day{:02}", day.as_u8()))
+            .join("mod.rs");
+        if mod_rs.exists() {
+            return Outcome::Present;
+        } else if self.dry_run {
+            return Outcome::Planned;
+        }
+
+        Outcome::from_result(generator.generate_module(src_dir, ModuleParameters { year, day }))
+    }
+}
+
+/// Stop on errors which would fail every remaining day.
+fn check_fatal(result: Result<(), WebError>) -> Result<Outcome, WebError> {
+    match result {
+        Err(e @ (WebError::Unauthenticated | WebError::RateLimited { .. })) => Err(e),
+        result => Ok(Outcome::from_result(result)),
+    }
+}
+
+/// Summary rows of synced days, the first row being the header.
+pub fn summary_rows(days: &[DaySync]) -> Vec<Vec<String>> {
+    let scaffold = days.iter().any(|d| d.module.is_some());
+    let mut header = vec!["Day".to_string(), "Input".into(), "Page".into()];
+    if scaffold {
+        header.push("Module".into());
+    }
+
+    let mut rows = vec![header];
+    for day in days {
+        let mut row = vec![
+            day.day.to_string(),
+            day.input.label("fetched", "to fetch"),
+            day.page.label("fetched", "to fetch"),
+        ];
+        if let Some(module) = &day.module {
+            row.push(module.label("created", "to create"));
+        }
+        rows.push(row);
+    }
+
+    rows
+}
+
+/// Print the summary of synced days, then the reason of each failure.
+pub fn print_sync_summary(days: &[DaySync]) {
+    print_table(&summary_rows(days));
+
+    for day in days {
+        let outcomes = [
+            ("input", Some(&day.input)),
+            ("page", Some(&day.page)),
+            ("module", day.module.as_ref()),
+        ];
+        for (name, outcome) in outcomes {
+            if let Some(Outcome::Failed(reason)) = outcome {
+                println!("Day {} {name}: {reason}", day.day);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_sx_codegen::ModuleGenerator;
+    use aoc_sx_core::exercise::{ExerciseDay, ExerciseYear};
+    use aoc_sx_mock::{fixtures_dir, Fixtures, MockServer};
+    use aoc_sx_webclient::{cache::Cache, throttle::Throttle, Client, Error as WebError};
+    use url::Url;
+
+    use super::{summary_rows, Outcome, Syncer};
+
+    #[test]
+    fn sync_days() {
+        let server = MockServer::start(Fixtures::new(fixtures_dir(), "mock-session")).unwrap();
+        let dir = std::env::temp_dir().join(format!("aoc-sx-sync-{}", std::process::id()));
+        let client = |session: &str| {
+            Client::new(session.into())
+                .with_base_url(Url::parse(server.url()).unwrap())
+                .with_throttle(Throttle::new(Duration::ZERO))
+        };
+        let year = ExerciseYear::try_from(2015).unwrap();
+        let day = |d: u8| ExerciseDay::try_from(d).unwrap();
+
+        let cached = client("mock-session").with_cache(Cache::new(dir.join("cache")));
+        let generator = ModuleGenerator::from_client(cached).with_inputs_dir(dir.join("inputs"));
+        let syncer = |dry_run| {
+            Syncer::new(generator.client(), dir.join("inputs"))
+                .with_dry_run(dry_run)
+                .with_scaffold(&generator, dir.join("src"))
+        };
+
+        let planned = syncer(true).sync_day(year, day(1)).unwrap();
+        assert_eq!(planned.input, Outcome::Planned);
+        assert_eq!(planned.module, Some(Outcome::Planned));
+        assert!(server.requests().is_empty());
+
+        let synced = syncer(false).sync_day(year, day(1)).unwrap();
+        assert_eq!(
+            (synced.input, synced.page, synced.module),
+            (Outcome::Done, Outcome::Done, Some(Outcome::Done))
+        );
+        assert!(dir.join("src/day01/mod.rs").exists());
+
+        let present = syncer(false).sync_day(year, day(1)).unwrap();
+        let failed = syncer(false).sync_day(year, day(2)).unwrap();
+        assert!(!present.is_failed() && failed.is_failed());
+        assert_eq!(
+            summary_rows(&[present, failed]),
+            [
+                ["Day", "Input", "Page", "Module"],
+                ["1", "present", "present", "present"],
+                ["2", "failed", "failed", "failed"],
+            ]
+        );
+
+        // A logged-out session stops the sync
+        let expired = client("expired");
+        assert!(matches!(
+            Syncer::new(&expired, dir.join("other-inputs")).sync_day(year, day(1)),
+            Err(WebError::Unauthenticated)
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
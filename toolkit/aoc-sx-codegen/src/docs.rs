@@ -0,0 +1,144 @@
+//! Module documentation blocks, and their changes.
+
+const DOC_PREFIX: &str = "//!";
+const PART_TWO_TITLE: &str = "--- Part Two ---";
+
+/// Line change in a documentation block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Added(String),
+    Removed(String),
+}
+
+/// Changes between two documentation blocks.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DocsDiff {
+    pub part_two_added: bool,
+    pub changes: Vec<Change>,
+}
+
+impl DocsDiff {
+    /// Compare two documentation blocks, line by line.
+    pub fn new(old: &str, new: &str) -> Self {
+        let old_lines = old.lines().collect::<Vec<_>>();
+        let new_lines = new.lines().collect::<Vec<_>>();
+
+        Self {
+            part_two_added: !old.contains(PART_TWO_TITLE) && new.contains(PART_TWO_TITLE),
+            changes: diff_lines(&old_lines, &new_lines),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl std::fmt::Display for DocsDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes.");
+        }
+
+        if self.part_two_added {
+            writeln!(f, "Part Two added.")?;
+        }
+
+        for change in &self.changes {
+            match change {
+                Change::Added(line) => writeln!(f, "+ {line}")?,
+                Change::Removed(line) => writeln!(f, "- {line}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Split a source file into its leading `//!` block and the rest.
+pub fn split_doc_block(source: &str) -> (&str, &str) {
+    let mut end = 0;
+    for line in source.split_inclusive('\n') {
+        if !line.starts_with(DOC_PREFIX) {
+            break;
+        }
+        end += line.len();
+    }
+
+    source.split_at(end)
+}
+
+/// Replace the leading `//!` block of a source file.
+pub fn replace_doc_block(source: &str, docs: &str) -> String {
+    let (_, rest) = split_doc_block(source);
+    format!("{docs}{rest}")
+}
+
+/// Line changes from `old` to `new`, using their longest common subsequence.
+fn diff_lines(old: &[&str], new: &[&str]) -> Vec<Change> {
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut changes = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lengths[i][j + 1] >= lengths[i + 1][j]) {
+            changes.push(Change::Added(new[j].to_owned()));
+            j += 1;
+        } else {
+            changes.push(Change::Removed(old[i].to_owned()));
+            i += 1;
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{replace_doc_block, split_doc_block, Change, DocsDiff};
+
+    const SOURCE: &str =
+        "//! # --- Day 1 ---\n//!\n//! Part one.\n\nuse std::fmt;\n\npub mod part1;\n";
+
+    #[test]
+    fn replace_keeps_code() {
+        let (docs, rest) = split_doc_block(SOURCE);
+        assert_eq!(docs, "//! # --- Day 1 ---\n//!\n//! Part one.\n");
+        assert_eq!(rest, "\nuse std::fmt;\n\npub mod part1;\n");
+
+        let updated = replace_doc_block(SOURCE, "//! # --- Day 1 ---\n//! Updated.\n");
+        assert_eq!(
+            updated,
+            "//! # --- Day 1 ---\n//! Updated.\n\nuse std::fmt;\n\npub mod part1;\n"
+        );
+    }
+
+    #[test]
+    fn diff_reports_part_two() {
+        let (old, _) = split_doc_block(SOURCE);
+        let new = format!("{old}//!\n//! # --- Part Two ---\n");
+
+        let diff = DocsDiff::new(old, &new);
+        assert!(diff.part_two_added);
+        assert_eq!(
+            diff.changes,
+            vec![
+                Change::Added("//!".into()),
+                Change::Added("//! # --- Part Two ---".into())
+            ]
+        );
+        assert!(DocsDiff::new(old, old).is_empty());
+    }
+}
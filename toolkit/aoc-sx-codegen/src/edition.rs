@@ -0,0 +1,322 @@
+//! Edition crates scaffolding.
+
+use std::path::{Component, Path, PathBuf};
+
+use aoc_sx_core::{exercise::ExerciseYear, input::workspace_root};
+use itertools::Itertools;
+
+use crate::progress::{render_editions, YearProgress};
+
+const EDITIONS_DIR: &str = "editions";
+const CLI_DIR: &str = "toolkit/aoc-sx-cli";
+const TOOLKIT_DIR: &str = "toolkit/aoc-sx";
+
+/// Creates edition crates, and registers them in the workspace, the CLI and the README.
+#[derive(Debug)]
+pub struct EditionGenerator {
+    root: PathBuf,
+    editions_dir: PathBuf,
+}
+
+impl EditionGenerator {
+    pub fn new<P: AsRef<Path>>(root: P) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            editions_dir: root.as_ref().join(EDITIONS_DIR),
+        }
+    }
+
+    /// Use a specific folder for the edition crates.
+    pub fn with_editions_dir<P: AsRef<Path>>(mut self, editions_dir: P) -> Self {
+        self.editions_dir = editions_dir.as_ref().to_path_buf();
+        self
+    }
+
+    /// Use the current workspace.
+    pub fn from_workspace() -> Self {
+        Self::new(workspace_root())
+    }
+
+    /// Source folder of an edition crate.
+    pub fn src_dir(&self, year: ExerciseYear) -> PathBuf {
+        self.crate_dir(year).join("src")
+    }
+
+    /// Create an edition crate if missing, and register it everywhere.
+    ///
+    /// Returns the created or modified files.
+    pub fn generate_edition(&self, year: ExerciseYear) -> std::io::Result<Vec<PathBuf>> {
+        let mut changed = vec![];
+
+        let crate_dir = self.crate_dir(year);
+        let cargo_toml = crate_dir.join("Cargo.toml");
+        if !cargo_toml.exists() {
+            std::fs::create_dir_all(self.src_dir(year))?;
+            std::fs::write(
+                &cargo_toml,
+                edition_cargo_toml(year, &self.toolkit_path(year)),
+            )?;
+            changed.push(cargo_toml);
+        }
+
+        let lib_rs = self.src_dir(year).join("lib.rs");
+        if !lib_rs.exists() {
+            std::fs::write(&lib_rs, crate::root_module(&[]))?;
+            changed.push(lib_rs);
+        }
+
+        let workspace_toml = self.root.join("Cargo.toml");
+        if update_file(&workspace_toml, |content| {
+            add_workspace_member(content, &self.crate_path_from(Path::new("."), year))
+        })? {
+            changed.push(workspace_toml);
+        }
+
+        let cli_toml = self.root.join(CLI_DIR).join("Cargo.toml");
+        let cli_path = self.crate_path_from(Path::new("../.."), year);
+        if update_file(&cli_toml, |content| {
+            add_cli_dependency(content, year, &cli_path)
+        })? {
+            changed.push(cli_toml);
+        }
+
+        let solutions_rs = self.root.join(CLI_DIR).join("src").join("solutions.rs");
+        let years = self.edition_years()?;
+        if update_file(&solutions_rs, |_| solutions_module(&years))? {
+            changed.push(solutions_rs);
+        }
+
+        let readme = self.root.join("README.md");
+        if update_file(&readme, |content| add_readme_edition(content, year))? {
+            changed.push(readme);
+        }
+
+        Ok(changed)
+    }
+
+    fn crate_dir(&self, year: ExerciseYear) -> PathBuf {
+        self.editions_dir.join(format!("aoc-{year}"))
+    }
+
+    /// Path of an edition crate in manifests, relative to `base` when inside the workspace.
+    fn crate_path_from(&self, base: &Path, year: ExerciseYear) -> String {
+        let path = match self.crate_dir(year).strip_prefix(&self.root) {
+            Ok(relative) => base.join(relative),
+            Err(_) => self.crate_dir(year),
+        };
+        path.to_string_lossy().replace('\\', "/")
+    }
+
+    /// Path of the toolkit crate in an edition manifest, relative to the edition crate when inside the workspace.
+    fn toolkit_path(&self, year: ExerciseYear) -> String {
+        let path = match self.crate_dir(year).strip_prefix(&self.root) {
+            Ok(relative) => {
+                let depth = relative
+                    .components()
+                    .filter(|c| matches!(c, Component::Normal(_)))
+                    .count();
+                std::iter::repeat_n("..", depth)
+                    .collect::<PathBuf>()
+                    .join(TOOLKIT_DIR)
+            }
+            Err(_) => self.root.join(TOOLKIT_DIR),
+        };
+        path.to_string_lossy().replace('\\', "/")
+    }
+
+    /// Years of the edition crates on disk.
+    pub fn edition_years(&self) -> std::io::Result<Vec<u16>> {
+        let mut years = vec![];
+        for entry in std::fs::read_dir(&self.editions_dir)? {
+            let name = entry?.file_name();
+            if let Some(year) = name
+                .to_str()
+                .and_then(|n| n.strip_prefix("aoc-"))
+                .and_then(|y| y.parse().ok())
+            {
+                years.push(year);
+            }
+        }
+
+        years.sort();
+        Ok(years)
+    }
+}
+
+/// Rewrite a file, returning whether its content changed.
+fn update_file(path: &Path, update: impl FnOnce(&str) -> String) -> std::io::Result<bool> {
+    let content = std::fs::read_to_string(path)?;
+    let updated = update(&content);
+    if updated == content {
+        return Ok(false);
+    }
+
+    std::fs::write(path, updated)?;
+    Ok(true)
+}
+
+fn edition_cargo_toml(year: ExerciseYear, toolkit_path: &str) -> String {
+    format!(
+        indoc::indoc! {r#"
+            [package]
+            name = "aoc-{year}"
+            version = "0.1.0"
+            edition = "2021"
+
+            # See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+            [dependencies]
+            aoc-sx = {{ path = "{toolkit_path}"}}
+            serde = {{ workspace = true }}
+            thiserror = {{ workspace = true }}
+        "#},
+        year = year,
+        toolkit_path = toolkit_path
+    )
+}
+
+/// Add a member to the `[workspace]` members list, keeping it sorted.
+fn add_workspace_member(content: &str, member: &str) -> String {
+    let entry = format!("  \"{member}\",");
+    let Some(start) = content.find("members = [\n") else {
+        return content.to_owned();
+    };
+    let start = start + "members = [\n".len();
+    let Some(len) = content[start..].find(']') else {
+        return content.to_owned();
+    };
+
+    let members = &content[start..start + len];
+    if members.lines().any(|l| l == entry) {
+        return content.to_owned();
+    }
+
+    let sorted = members
+        .lines()
+        .map(str::to_owned)
+        .chain(std::iter::once(entry))
+        .sorted()
+        .join("\n");
+    format!("{}{sorted}\n{}", &content[..start], &content[start + len..])
+}
+
+/// Add the edition dependency to the CLI manifest, after the other editions.
+fn add_cli_dependency(content: &str, year: ExerciseYear, path: &str) -> String {
+    let dependency = format!("aoc-{year} = {{ path = \"{path}\" }}");
+    if content.lines().any(|l| l == dependency) {
+        return content.to_owned();
+    }
+
+    insert_sorted_line(content, &dependency, |line| {
+        line.starts_with("aoc-2") || line.starts_with("aoc-sx")
+    })
+}
+
+/// Add the edition to the README editions list.
+fn add_readme_edition(content: &str, year: ExerciseYear) -> String {
+    let prefix = format!("- **{year}** ");
+    if content.lines().any(|l| l.starts_with(&prefix)) {
+        return content.to_owned();
+    }
+
+    let line = render_editions(&[YearProgress::empty(year)], false);
+    insert_sorted_line(content, line.trim_end(), |line| line.starts_with("- **20"))
+}
+
+/// Insert a line in the first block of consecutive lines matching a predicate, keeping it sorted.
+fn insert_sorted_line(content: &str, new_line: &str, in_block: impl Fn(&str) -> bool) -> String {
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let Some(start) = lines.iter().position(|l| in_block(l)) else {
+        return content.to_owned();
+    };
+    let end = start + lines[start..].iter().take_while(|l| in_block(l)).count();
+
+    let position = start + lines[start..end].partition_point(|l| *l < new_line);
+    lines.insert(position, new_line);
+
+    let mut output = lines.join("\n");
+    if content.ends_with('\n') {
+        output.push('\n');
+    }
+    output
+}
+
+/// CLI module registering every edition.
+fn solutions_module(years: &[u16]) -> String {
+    let mut output = String::new();
+
+    output.push_str("//! Solutions of every edition.\n\n");
+    output.push_str("use aoc_sx_core::solution::Registry;\n\n");
+    output.push_str("/// Build the registry of every edition.\n");
+    output.push_str("pub fn registry() -> Registry {\n");
+    output.push_str("    let mut registry = Registry::new();\n");
+    for year in years {
+        output.push_str(&format!("    aoc_{year}::register(&mut registry);\n"));
+    }
+    output.push_str("    registry\n");
+    output.push_str("}\n");
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use aoc_sx_core::exercise::ExerciseYear;
+
+    use super::{
+        add_cli_dependency, add_readme_edition, add_workspace_member, edition_cargo_toml,
+        EditionGenerator,
+    };
+
+    #[test]
+    fn register_edition() {
+        let year = ExerciseYear::try_from(2020).unwrap();
+
+        let workspace =
+            "[workspace]\nmembers = [\n  \"./editions/aoc-2019\",\n  \"./toolkit/aoc-sx\",\n]\n";
+        let updated = add_workspace_member(workspace, "./editions/aoc-2020");
+        assert_eq!(
+            updated,
+            "[workspace]\nmembers = [\n  \"./editions/aoc-2019\",\n  \"./editions/aoc-2020\",\n  \"./toolkit/aoc-sx\",\n]\n"
+        );
+        assert_eq!(
+            add_workspace_member(&updated, "./editions/aoc-2020"),
+            updated
+        );
+
+        let cli = "[dependencies]\nclap = \"4\"\n\naoc-2019 = { path = \"../../editions/aoc-2019\" }\naoc-sx-core = { path = \"../aoc-sx-core\" }\n";
+        assert!(add_cli_dependency(cli, year, "../../editions/aoc-2020").contains(
+            "aoc-2019 = { path = \"../../editions/aoc-2019\" }\naoc-2020 = { path = \"../../editions/aoc-2020\" }\naoc-sx-core"
+        ));
+
+        let readme =
+            "## Editions\n\n- **2019** (**25/25**, 50/50 stars)\n\n## Toolkit\n\n- **Bold** item\n";
+        assert_eq!(
+            add_readme_edition(readme, year),
+            "## Editions\n\n- **2019** (**25/25**, 50/50 stars)\n- **2020** (**0/25**, 0/50 stars)\n\n## Toolkit\n\n- **Bold** item\n"
+        );
+    }
+
+    #[test]
+    fn nested_editions_dir() {
+        let year = ExerciseYear::try_from(2020).unwrap();
+
+        let default = EditionGenerator::new("/workspace");
+        assert_eq!(default.toolkit_path(year), "../../toolkit/aoc-sx");
+
+        let nested =
+            EditionGenerator::new("/workspace").with_editions_dir("/workspace/puzzles/aoc");
+        assert_eq!(
+            nested.crate_path_from(Path::new("."), year),
+            "./puzzles/aoc/aoc-2020"
+        );
+        assert_eq!(nested.toolkit_path(year), "../../../toolkit/aoc-sx");
+        assert!(edition_cargo_toml(year, &nested.toolkit_path(year))
+            .contains("aoc-sx = { path = \"../../../toolkit/aoc-sx\"}"));
+
+        let outside = EditionGenerator::new("/workspace").with_editions_dir("/elsewhere");
+        assert_eq!(outside.toolkit_path(year), "/workspace/toolkit/aoc-sx");
+    }
+}
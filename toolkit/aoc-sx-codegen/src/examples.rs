@@ -0,0 +1,131 @@
+//! Example inputs and expected sample answers, from day pages.
+
+use aoc_sx_core::exercise::ExercisePart;
+use scraper::{ElementRef, Html, Selector};
+
+/// Examples of a part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartExamples {
+    /// Candidate example inputs, in page order.
+    pub inputs: Vec<String>,
+    /// Likely expected answer for the examples.
+    pub expected_answer: Option<String>,
+}
+
+/// Examples of a day page, one entry per part description.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayExamples {
+    parts: Vec<PartExamples>,
+}
+
+impl DayExamples {
+    /// Extract examples from a day page.
+    pub fn from_html(html_content: &str) -> Self {
+        let document = Html::parse_document(html_content);
+        let selector = Selector::parse("article.day-desc").unwrap();
+
+        Self {
+            parts: document
+                .root_element()
+                .select(&selector)
+                .map(|node| extract_part(&node))
+                .collect(),
+        }
+    }
+
+    /// Examples of a part, if its description is present.
+    pub fn part(&self, part: ExercisePart) -> Option<&PartExamples> {
+        self.parts.get(part.as_u8() as usize - 1)
+    }
+
+    /// Example inputs of a part, falling back to the first part ones when the second part has none.
+    pub fn inputs(&self, part: ExercisePart) -> &[String] {
+        match self.part(part) {
+            Some(examples) if !examples.inputs.is_empty() => &examples.inputs,
+            _ if part == ExercisePart::Second => self.inputs(ExercisePart::First),
+            _ => &[],
+        }
+    }
+
+    /// Likely expected answer of a part.
+    pub fn expected_answer(&self, part: ExercisePart) -> Option<&str> {
+        self.part(part)?.expected_answer.as_deref()
+    }
+}
+
+fn extract_part(node: &ElementRef) -> PartExamples {
+    let pre_selector = Selector::parse("pre").unwrap();
+    // Answers are emphasized code, in either nesting order
+    let answer_selector = Selector::parse("code > em, em > code").unwrap();
+
+    let inputs = node
+        .select(&pre_selector)
+        .map(|pre| pre.text().collect::<String>())
+        .filter(|text| !text.trim().is_empty())
+        .map(|text| format!("{}\n", text.trim_end()))
+        .collect();
+
+    let expected_answer = node
+        .select(&answer_selector)
+        // Skip emphasized code inside examples
+        .filter(|e| {
+            !e.ancestors()
+                .any(|a| a.value().as_element().is_some_and(|a| a.name() == "pre"))
+        })
+        .last()
+        .map(|e| e.text().collect::<String>().trim().to_owned())
+        .filter(|answer| !answer.is_empty());
+
+    PartExamples {
+        inputs,
+        expected_answer,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_sx_core::exercise::ExercisePart;
+
+    use super::DayExamples;
+
+    const PAGE: &str = r#"<html><body><main>
+        <article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+        <p>For example:</p>
+        <pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+        <p>In this example, the calibration values are <code>12</code> and <code>38</code>.
+        Adding these together produces <code><em>50</em></code>.</p>
+        </article>
+        <p>Your puzzle answer was <code>54388</code>.</p>
+        <article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+        <p>Using the same list, with <em>some</em> <code>code</code>, the total is <em><code>281</code></em>.</p>
+        </article>
+    </main></body></html>"#;
+
+    #[test]
+    fn extract_examples_and_answers() {
+        let examples = DayExamples::from_html(PAGE);
+
+        assert_eq!(
+            examples.inputs(ExercisePart::First),
+            ["1abc2\npqr3stu8vwx\n"]
+        );
+        assert_eq!(examples.expected_answer(ExercisePart::First), Some("50"));
+
+        // Second part reuses the first part examples
+        assert_eq!(
+            examples.inputs(ExercisePart::Second),
+            examples.inputs(ExercisePart::First)
+        );
+        assert_eq!(examples.expected_answer(ExercisePart::Second), Some("281"));
+    }
+
+    #[test]
+    fn missing_second_part() {
+        let examples = DayExamples::from_html(PAGE.split("<p>Your puzzle").next().unwrap());
+
+        assert!(examples.part(ExercisePart::Second).is_none());
+        assert_eq!(examples.expected_answer(ExercisePart::Second), None);
+    }
+}
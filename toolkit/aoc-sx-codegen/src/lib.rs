@@ -1,17 +1,46 @@
+pub mod docs;
+pub mod edition;
+pub mod examples;
 pub mod parser;
+pub mod progress;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use aoc_sx_core::exercise::{ExerciseDay, ExerciseYear};
+use aoc_sx_core::{
+    exercise::{ExerciseDay, ExercisePart, ExerciseYear},
+    input::{default_inputs_dir, input_path},
+};
 use aoc_sx_webclient::Client;
+use docs::{replace_doc_block, split_doc_block, DocsDiff};
+use examples::DayExamples;
 use itertools::Itertools;
 use parser::MarkdownContent;
 
 use crate::parser::DayPageParser;
 
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not fetch day {day} of {year}: {source}")]
+    Client {
+        year: ExerciseYear,
+        day: ExerciseDay,
+        source: aoc_sx_webclient::Error,
+    },
+
+    #[error("Module folder {0:?} has no parent edition folder.")]
+    NoEditionFolder(PathBuf),
+
+    #[error("README has no '{0}' / '{1}' markers.")]
+    NoEditionsMarkers(&'static str, &'static str),
+}
+
 #[derive(Debug)]
 pub struct ModuleGenerator {
     client: Client,
+    inputs_dir: PathBuf,
 }
 
 #[derive(Debug)]
@@ -20,26 +49,46 @@ pub struct ModuleParameters {
     pub day: ExerciseDay,
 }
 
+impl ModuleParameters {
+    fn client_error(&self, source: aoc_sx_webclient::Error) -> Error {
+        Error::Client {
+            year: self.year,
+            day: self.day,
+            source,
+        }
+    }
+}
+
 impl ModuleGenerator {
     pub fn new(session_token: String) -> Self {
+        Self::from_client(Client::new(session_token))
+    }
+
+    /// Use an already configured client.
+    pub fn from_client(client: Client) -> Self {
         Self {
-            client: Client::new(session_token),
+            client,
+            inputs_dir: default_inputs_dir(),
         }
     }
 
+    /// Use a specific inputs directory.
+    pub fn with_inputs_dir(mut self, inputs_dir: PathBuf) -> Self {
+        self.inputs_dir = inputs_dir;
+        self
+    }
+
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
     /// Generate a Rust module.
     pub fn generate_module<P: AsRef<Path>>(
         &self,
         folder: P,
         parameters: ModuleParameters,
-    ) -> std::io::Result<()> {
-        let exercise_page = self
-            .client
-            .fetch_exercise_page(parameters.year, parameters.day)
-            .unwrap();
-
-        let markdown = DayPageParser::new(exercise_page.page_url, exercise_page.input_url)
-            .parse(exercise_page.page_content.as_str());
+    ) -> Result<(), Error> {
+        let (markdown, examples) = self.fetch_day_page(&parameters)?;
 
         let path = folder
             .as_ref()
@@ -50,23 +99,34 @@ impl ModuleGenerator {
             std::fs::create_dir_all(&path)?;
         }
 
-        // Then, create mod.rs
+        // Then, create mod.rs, or only refresh its docs
         let mod_rs = path.join("mod.rs");
-        println!("Creating {mod_rs:?} ...");
-        std::fs::write(&mod_rs, self.markdown_to_mod_rs(&markdown))?;
+        if mod_rs.exists() {
+            println!("Updating docs of {mod_rs:?} ...");
+            print!("{}", self.update_docs(&mod_rs, &markdown)?);
+        } else {
+            println!("Creating {mod_rs:?} ...");
+            std::fs::write(&mod_rs, self.markdown_to_mod_rs(&markdown, &parameters))?;
+        }
 
         // Then, create part1.rs
         let part1_rs = path.join("part1.rs");
         if !part1_rs.exists() {
             println!("Creating {part1_rs:?} ...");
-            std::fs::write(&part1_rs, self.scaffold_code_part("Part 1"))?;
+            std::fs::write(
+                &part1_rs,
+                self.scaffold_code_part(ExercisePart::First, &parameters, &examples),
+            )?;
         }
 
         // Then, create part2.rs
         let part2_rs = path.join("part2.rs");
         if !part2_rs.exists() {
             println!("Creating {part2_rs:?} ...");
-            std::fs::write(&part2_rs, self.scaffold_code_part("Part 2"))?;
+            std::fs::write(
+                &part2_rs,
+                self.scaffold_code_part(ExercisePart::Second, &parameters, &examples),
+            )?;
         }
 
         // Then, create common.rs
@@ -76,37 +136,127 @@ impl ModuleGenerator {
             std::fs::write(&common_rs, "//! Common")?;
         }
 
-        // Finally, create input.txt
-        let input_txt = path.join("input.txt");
+        // Finally, create the input file
+        let input_txt = input_path(&self.inputs_dir, parameters.year, parameters.day);
         if !input_txt.exists() {
+            let puzzle_input = self
+                .client
+                .fetch_input_page(parameters.year, parameters.day)
+                .map_err(|source| parameters.client_error(source))?;
+
             println!("Creating {input_txt:?} ...");
-            std::fs::write(&input_txt, exercise_page.puzzle_input.as_str())?;
+            if let Some(parent) = input_txt.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&input_txt, puzzle_input.as_str())?;
         }
 
         // Generate root module
-        let root_module = path.parent().unwrap();
+        let root_module = path
+            .parent()
+            .ok_or_else(|| Error::NoEditionFolder(path.clone()))?;
         let lib_rs = root_module.join("lib.rs");
         println!("Creating {lib_rs:?} ...");
-        std::fs::write(&lib_rs, self.generate_root_module(root_module))?;
+        std::fs::write(&lib_rs, self.generate_root_module(root_module)?)?;
 
         Ok(())
     }
 
-    fn markdown_to_mod_rs(&self, md: &MarkdownContent) -> String {
+    /// Regenerate the `//!` block of an existing module from the latest page, keeping its code.
+    pub fn update_module_docs<P: AsRef<Path>>(
+        &self,
+        folder: P,
+        parameters: ModuleParameters,
+    ) -> Result<DocsDiff, Error> {
+        let (markdown, _) = self.fetch_day_page(&parameters)?;
+        let mod_rs = folder
+            .as_ref()
+            .join(format!("day{:02}", parameters.day.as_u8()))
+            .join("mod.rs");
+
+        Ok(self.update_docs(&mod_rs, &markdown)?)
+    }
+
+    fn fetch_day_page(
+        &self,
+        parameters: &ModuleParameters,
+    ) -> Result<(MarkdownContent, DayExamples), Error> {
+        let exercise_page = self
+            .client
+            .fetch_exercise_page(parameters.year, parameters.day)
+            .map_err(|source| parameters.client_error(source))?;
+
+        let examples = DayExamples::from_html(exercise_page.page_content.as_str());
+        let markdown = DayPageParser::new(exercise_page.page_url, exercise_page.input_url)
+            .parse(exercise_page.page_content.as_str());
+
+        Ok((markdown, examples))
+    }
+
+    fn update_docs(&self, mod_rs: &Path, md: &MarkdownContent) -> std::io::Result<DocsDiff> {
+        let source = std::fs::read_to_string(mod_rs)?;
+        let (old_docs, _) = split_doc_block(&source);
+        let new_docs = self.markdown_to_rust_comment(md);
+
+        let diff = DocsDiff::new(old_docs, &new_docs);
+        if !diff.is_empty() {
+            std::fs::write(mod_rs, replace_doc_block(&source, &new_docs))?;
+        }
+
+        Ok(diff)
+    }
+
+    fn markdown_to_mod_rs(&self, md: &MarkdownContent, parameters: &ModuleParameters) -> String {
         let mut output = String::new();
         output.push_str(&self.markdown_to_rust_comment(md));
 
+        // Imports
+        output.push('\n');
+        output.push_str("use aoc_sx::core::{exercise::Answer, solution::Solution};\n");
+
         // Module declarations
         output.push('\n');
         output.push_str("pub mod common;\n");
         output.push_str("pub mod part1;\n");
         output.push_str("pub mod part2;\n");
+
+        // Solution
         output.push('\n');
-        output.push_str("const INPUT: &str = include_str!(\"./input.txt\");\n");
+        output.push_str(&self.scaffold_solution(parameters));
 
         output
     }
 
+    fn scaffold_solution(&self, parameters: &ModuleParameters) -> String {
+        format!(
+            indoc::indoc! {r###"
+                /// Day solution.
+                pub struct Day;
+
+                impl Solution for Day {{
+                    const YEAR: u16 = {year};
+                    const DAY: u8 = {day};
+
+                    type Input<'a> = &'a str;
+
+                    fn parse(input: &str) -> Self::Input<'_> {{
+                        input
+                    }}
+
+                    fn part1(input: &Self::Input<'_>) -> impl Into<Answer> {{
+                        part1::run(input)
+                    }}
+
+                    fn part2(input: &Self::Input<'_>) -> Option<impl Into<Answer>> {{
+                        Some(part2::run(input))
+                    }}
+                }}
+            "###},
+            year = parameters.year,
+            day = parameters.day
+        )
+    }
+
     fn markdown_to_rust_comment(&self, md: &MarkdownContent) -> String {
         let mut output = String::new();
 
@@ -121,40 +271,203 @@ impl ModuleGenerator {
         output
     }
 
-    fn scaffold_code_part(&self, title: &str) -> String {
+    fn scaffold_code_part(
+        &self,
+        part: ExercisePart,
+        parameters: &ModuleParameters,
+        examples: &DayExamples,
+    ) -> String {
         let mut output = String::new();
 
-        output.push_str(&format!("//! {title}\n\n"));
+        output.push_str(&format!("//! Part {}\n\n", part.as_u8()));
         output.push_str(indoc::indoc! {r###"
-            use super::INPUT;
-
-            pub fn run() -> usize {
+            pub fn run(_input: &str) -> usize {
                 0
             }
 
             #[cfg(test)]
             mod tests {
-                #[test]
-                fn run() {
-                    assert_eq!(super::run(), 0)
-                }
-            }
         "###});
 
+        let sample = examples.inputs(part).first();
+        let imports = if sample.is_some() {
+            indoc::indoc! {r###"
+                use aoc_sx::{
+                    core::{
+                        exercise::ExercisePart,
+                        testing::{check_answer, test_input},
+                    },
+                    indoc::indoc,
+                };
+            "###}
+        } else {
+            indoc::indoc! {r###"
+                use aoc_sx::core::{
+                    exercise::ExercisePart,
+                    testing::{check_answer, test_input},
+                };
+            "###}
+        };
+        output.push_str(&indent(imports));
+
+        output.push_str(&format!(
+            "\n    use crate::day{day:02}::Day;\n\n",
+            day = parameters.day.as_u8()
+        ));
+
+        if let Some(sample) = sample {
+            output.push_str(&self.scaffold_sample_test(sample, examples.expected_answer(part)));
+        }
+
+        output.push_str(&indent(&format!(
+            indoc::indoc! {r###"
+                #[test]
+                fn run() {{
+                    let Some(input) = test_input::<Day>() else {{
+                        return;
+                    }};
+                    check_answer::<Day>(ExercisePart::{part}, &input, super::run(&input));
+                }}
+            "###},
+            part = format!("{part:?}")
+        )));
+        output.push_str("}\n");
+
         output
     }
 
-    fn generate_root_module(&self, path: &Path) -> String {
+    /// Sample test, ignored until the part is solved.
+    fn scaffold_sample_test(&self, sample: &str, expected_answer: Option<&str>) -> String {
         let mut output = String::new();
 
-        let directory = std::fs::read_dir(path).unwrap();
-        for file in directory.map(|e| e.unwrap().file_name()).sorted() {
-            let filename = file.into_string().unwrap();
-            if !filename.ends_with(".rs") {
-                output.push_str(&format!("pub mod {filename};\n"));
+        output.push_str("    const SAMPLE_DATA: &str = indoc! {\"\n");
+        for line in sample.trim_end().lines() {
+            let line = line.replace('\\', "\\\\").replace('"', "\\\"");
+            if line.is_empty() {
+                output.push('\n');
+            } else {
+                output.push_str(&format!("        {line}\n"));
             }
         }
+        // Close the literal on the last line, without a trailing newline
+        output.pop();
+        output.push_str("\"\n    };\n\n");
+
+        let assertion = match expected_answer {
+            // Bare literals only when they fit the `usize` returned by the scaffold
+            Some(answer) if answer.parse::<usize>().is_ok() => {
+                format!("assert_eq!(super::run(SAMPLE_DATA), {answer});")
+            }
+            Some(answer) => format!(
+                "assert_eq!(super::run(SAMPLE_DATA).to_string(), \"{}\");",
+                answer.replace('\\', "\\\\").replace('"', "\\\"")
+            ),
+            None => "// TODO: expected sample answer\n    assert_eq!(super::run(SAMPLE_DATA), 0);"
+                .to_owned(),
+        };
+
+        output.push_str(&indent(&format!(
+            indoc::indoc! {r###"
+                #[test]
+                #[ignore = "not solved yet"]
+                fn sample() {{
+                    {assertion}
+                }}
+            "###},
+            assertion = assertion
+        )));
+        output.push('\n');
 
         output
     }
+
+    fn generate_root_module(&self, path: &Path) -> std::io::Result<String> {
+        let modules = std::fs::read_dir(path)?
+            .map(|entry| entry.map(|e| e.file_name()))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter_map(|filename| filename.into_string().ok())
+            .filter(|filename| !filename.ends_with(".rs"))
+            .sorted()
+            .collect::<Vec<_>>();
+
+        Ok(root_module(&modules))
+    }
+}
+
+/// Root module of an edition, declaring and registering its days.
+fn root_module(modules: &[String]) -> String {
+    let mut output = String::new();
+
+    for module in modules {
+        output.push_str(&format!("pub mod {module};\n"));
+    }
+
+    // Registry
+    if !modules.is_empty() {
+        output.push('\n');
+    }
+    output.push_str("use aoc_sx::core::solution::Registry;\n");
+    output.push('\n');
+    output.push_str("/// Register every solved day of this edition.\n");
+    if modules.is_empty() {
+        output.push_str("pub fn register(_registry: &mut Registry) {}\n");
+        return output;
+    }
+
+    output.push_str("pub fn register(registry: &mut Registry) {\n");
+    for module in modules {
+        output.push_str(&format!("    registry.register(&{module}::Day);\n"));
+    }
+    output.push_str("}\n");
+
+    output
+}
+
+/// Indent non-empty lines by one level.
+fn indent(code: &str) -> String {
+    code.lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_owned()
+            } else {
+                format!("    {line}\n")
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_sx_core::exercise::{ExerciseDay, ExercisePart, ExerciseYear};
+
+    use crate::{examples::DayExamples, ModuleGenerator, ModuleParameters};
+
+    const PAGE: &str = r#"<article class="day-desc"><h2>--- Day 1: Test ---</h2>
+        <pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+        <p>Adding these together produces <code><em>50</em></code>.</p>
+        </article>"#;
+
+    #[test]
+    fn scaffold_sample_test() {
+        let generator = ModuleGenerator::new(String::new());
+        let parameters = ModuleParameters {
+            year: ExerciseYear::try_from(2023).unwrap(),
+            day: ExerciseDay::try_from(1).unwrap(),
+        };
+        let examples = DayExamples::from_html(PAGE);
+
+        let code = generator.scaffold_code_part(ExercisePart::First, &parameters, &examples);
+        assert!(code.contains(
+            "    const SAMPLE_DATA: &str = indoc! {\"\n        1abc2\n        pqr3stu8vwx\"\n    };\n"
+        ));
+        assert!(code.contains("    #[ignore = \"not solved yet\"]\n"));
+        assert!(code.contains("        assert_eq!(super::run(SAMPLE_DATA), 50);\n"));
+
+        // A negative answer does not fit the scaffold return type
+        let negative = generator.scaffold_sample_test("1abc2", Some("-3"));
+        assert!(negative.contains("assert_eq!(super::run(SAMPLE_DATA).to_string(), \"-3\");"));
+    }
 }
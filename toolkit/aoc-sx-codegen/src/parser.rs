@@ -1,4 +1,5 @@
-use scraper::{ElementRef, Html, Selector};
+use ego_tree::NodeRef;
+use scraper::{ElementRef, Html, Node, Selector};
 use url::Url;
 
 #[derive(Debug, Default)]
@@ -10,6 +11,13 @@ pub struct DayPageParser {
     input_url: Url,
 }
 
+/// Conversion state, shared by the articles of a page.
+#[derive(Debug, Default)]
+struct Converter {
+    /// Hover texts, rendered as footnotes.
+    footnotes: Vec<String>,
+}
+
 impl DayPageParser {
     pub fn new(page_url: Url, input_url: Url) -> Self {
         Self {
@@ -20,13 +28,23 @@ impl DayPageParser {
 
     pub fn parse(&self, html_content: &str) -> MarkdownContent {
         let mut md = MarkdownContent::new();
+        let mut converter = Converter::default();
 
         let document = Html::parse_document(html_content);
         let selector = Selector::parse("article[class='day-desc']").unwrap();
         let nodes = document.root_element().select(&selector);
         for node in nodes {
-            md.push_content(self.convert_html_to_markdown(&node));
+            md.push_content(self.convert_html_to_markdown(&node, &mut converter));
+        }
+
+        for (idx, footnote) in converter.footnotes.iter().enumerate() {
+            md.push_content(MarkdownContent(format!(
+                "[^{}]: {}\n\n",
+                idx + 1,
+                escape(footnote)
+            )));
         }
+        md.0.truncate(md.0.trim_end().len());
 
         // Insert links at second line
         md.insert_at_line(
@@ -40,41 +58,222 @@ impl DayPageParser {
         md
     }
 
-    fn convert_html_to_markdown(&self, node: &ElementRef) -> MarkdownContent {
+    fn convert_html_to_markdown(
+        &self,
+        node: &ElementRef,
+        converter: &mut Converter,
+    ) -> MarkdownContent {
         let mut output = String::new();
 
-        for child in node.children() {
-            if let Some(tag) = child.value().as_element() {
-                if tag.name() == "h2" {
-                    output.push_str(&format!(
-                        "# {}\n",
-                        ElementRef::wrap(child).unwrap().text().collect::<String>()
-                    ));
-                } else if tag.name() == "p" {
-                    output.push_str(&ElementRef::wrap(child).unwrap().inner_html());
-                } else if tag.name() == "pre" {
-                    output.push_str("```text\n");
-                    output.push_str(&ElementRef::wrap(child).unwrap().text().collect::<String>());
-                    output.push_str("```");
-                } else if tag.name() == "ul" {
-                    for child in child.children() {
-                        if child.value().is_element() {
-                            output.push_str(&format!(
-                                "- {}\n",
-                                ElementRef::wrap(child).unwrap().text().collect::<String>()
-                            ));
-                        }
+        for child in node.children().filter_map(ElementRef::wrap) {
+            let block = match child.value().name() {
+                "h2" => format!(
+                    "# {}",
+                    collapse_whitespace(&child.text().collect::<String>())
+                ),
+                "p" => escape_block_start(self.convert_inline(*child, converter).trim()),
+                "pre" => {
+                    let text = child.text().collect::<String>();
+                    format!("```text\n{}\n```", text.trim_end_matches('\n'))
+                }
+                "ul" | "ol" => self.convert_list(&child, 0, converter),
+                _ => continue,
+            };
+
+            output.push_str(&block);
+            output.push_str("\n\n");
+        }
+
+        MarkdownContent(output)
+    }
+
+    /// Convert a list, with nested lists indented under their item.
+    fn convert_list(&self, list: &ElementRef, depth: usize, converter: &mut Converter) -> String {
+        let ordered = list.value().name() == "ol";
+        let indent = "  ".repeat(depth);
+        let mut lines = vec![];
+
+        for (idx, item) in list
+            .children()
+            .filter_map(ElementRef::wrap)
+            .filter(|e| e.value().name() == "li")
+            .enumerate()
+        {
+            let marker = if ordered {
+                format!("{}.", idx + 1)
+            } else {
+                "-".to_owned()
+            };
+
+            let mut text = String::new();
+            let mut nested = vec![];
+            for child in item.children() {
+                match ElementRef::wrap(child) {
+                    Some(e) if matches!(e.value().name(), "ul" | "ol") => {
+                        nested.push(self.convert_list(&e, depth + 1, converter));
+                    }
+                    Some(e) if e.value().name() == "p" => {
+                        text.push_str(&self.convert_inline(*e, converter));
+                        text.push(' ');
                     }
+                    _ => text.push_str(&self.convert_node(child, converter)),
                 }
             }
 
-            output.push('\n');
+            lines.push(format!(
+                "{indent}{marker} {}",
+                escape_block_start(collapse_whitespace(&text).trim())
+            ));
+            lines.extend(nested);
         }
 
-        MarkdownContent(output)
+        lines.join("\n")
+    }
+
+    /// Convert the children of a node to inline Markdown.
+    fn convert_inline(&self, node: NodeRef<Node>, converter: &mut Converter) -> String {
+        let text = node
+            .children()
+            .map(|child| self.convert_node(child, converter))
+            .collect::<String>();
+
+        collapse_whitespace(&text)
+    }
+
+    fn convert_node(&self, node: NodeRef<Node>, converter: &mut Converter) -> String {
+        if let Some(text) = node.value().as_text() {
+            return escape(&collapse_whitespace(text));
+        }
+
+        let Some(element) = ElementRef::wrap(node) else {
+            return String::new();
+        };
+
+        match element.value().name() {
+            "em" | "i" | "strong" | "b" => {
+                let inner = self.convert_inline(node, converter);
+                wrap_emphasis(&inner)
+            }
+            "code" => {
+                // Emphasis cannot be nested in code spans, so it goes around
+                let span = code_span(&collapse_whitespace(&element.text().collect::<String>()));
+                let emphasized = element
+                    .descendants()
+                    .filter_map(ElementRef::wrap)
+                    .any(|e| e.value().name() == "em");
+
+                if emphasized {
+                    wrap_emphasis(&span)
+                } else {
+                    span
+                }
+            }
+            "a" => {
+                let text = self.convert_inline(node, converter);
+                match element.attr("href") {
+                    Some(href) => {
+                        let url = self
+                            .page_url
+                            .join(href)
+                            .map(|u| u.to_string())
+                            .unwrap_or_else(|_| href.to_owned());
+                        format!("[{}]({url})", text.trim())
+                    }
+                    None => text,
+                }
+            }
+            "span" => {
+                let text = self.convert_inline(node, converter);
+                match element.attr("title") {
+                    Some(title) => {
+                        converter.footnotes.push(collapse_whitespace(title));
+                        format!("{text}[^{}]", converter.footnotes.len())
+                    }
+                    None => text,
+                }
+            }
+            "br" => " ".to_owned(),
+            _ => self.convert_inline(node, converter),
+        }
     }
 }
 
+/// Replace whitespace runs (including newlines) by a single space.
+fn collapse_whitespace(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut in_whitespace = false;
+
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                output.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            output.push(c);
+            in_whitespace = false;
+        }
+    }
+
+    output
+}
+
+/// Escape characters with a meaning in inline Markdown.
+fn escape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>') {
+            output.push('\\');
+        }
+        output.push(c);
+    }
+    output
+}
+
+/// Escape a leading character which would start another block (heading, list, quote).
+fn escape_block_start(text: &str) -> String {
+    // Ordered list markers: up to 9 digits, then `.` or `)`, then a space or nothing
+    let digits = text.chars().take_while(char::is_ascii_digit).count();
+    let (number, rest) = text.split_at(digits);
+    let mut delimiter = rest.chars();
+    if (1..=9).contains(&digits)
+        && matches!(delimiter.next(), Some('.' | ')'))
+        && delimiter.next().map_or(true, |c| c == ' ')
+    {
+        return format!("{number}\\{rest}");
+    }
+
+    match text.chars().next() {
+        Some('#' | '-' | '+') => format!("\\{text}"),
+        _ => text.to_owned(),
+    }
+}
+
+/// Emphasize text, keeping surrounding spaces outside of the markers.
+fn wrap_emphasis(text: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_owned();
+    }
+
+    let leading = if text.starts_with(' ') { " " } else { "" };
+    let trailing = if text.ends_with(' ') { " " } else { "" };
+    format!("{leading}**{trimmed}**{trailing}")
+}
+
+/// Code span, with a fence longer than any backtick run of the code.
+fn code_span(code: &str) -> String {
+    let longest_run = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run + 1);
+    let padding = if code.starts_with('`') || code.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+
+    format!("{fence}{padding}{code}{padding}{fence}")
+}
+
 impl MarkdownContent {
     pub fn new() -> Self {
         Self(String::new())
@@ -94,3 +293,72 @@ impl MarkdownContent {
         self.0 = lines.join("\n");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::DayPageParser;
+
+    fn parse(html: &str) -> String {
+        let parser = DayPageParser::new(
+            Url::parse("https://adventofcode.com/2023/day/10").unwrap(),
+            Url::parse("https://adventofcode.com/2023/day/10/input").unwrap(),
+        );
+        parser.parse(html).as_str().to_owned()
+    }
+
+    #[test]
+    fn inline_markup() {
+        let md = parse(
+            r#"<article class="day-desc"><h2>--- Day 10: Pipe Maze ---</h2>
+            <p>Signposts labeled "<a target="_blank" href="https://en.wikipedia.org/wiki/Hot_spring">Hot Springs</a>"
+            and <a href="/2023/day/10/input">your input</a>.</p>
+            <p>The field is <span title="Manufactured by Hamilton &amp; Hilbert">densely packed</span>;
+            a grid of <em>tiles</em>, with <code>S</code> &lt; <code><em>8</em></code> and <em><code>4</code></em>.</p>
+            </article>"#,
+        );
+
+        assert_eq!(
+            md,
+            "# --- Day 10: Pipe Maze ---\n\
+             \n\
+             > _Exercise page: <https://adventofcode.com/2023/day/10>_\n\
+             \n\
+             > _Input page: <https://adventofcode.com/2023/day/10/input>_\n\
+             \n\
+             Signposts labeled \"[Hot Springs](https://en.wikipedia.org/wiki/Hot_spring)\" \
+             and [your input](https://adventofcode.com/2023/day/10/input).\n\
+             \n\
+             The field is densely packed[^1]; a grid of **tiles**, with `S` \\< **`8`** and **`4`**.\n\
+             \n\
+             [^1]: Manufactured by Hamilton & Hilbert"
+        );
+    }
+
+    #[test]
+    fn lists_and_parts() {
+        let md = parse(
+            r#"<article class="day-desc"><h2>--- Day 1: Lists ---</h2>
+            <ul>
+            <li><code>|</code> is a <em>vertical</em> pipe.</li>
+            <li>Nested:
+              <ul><li>first</li><li>second</li></ul>
+            </li>
+            </ul>
+            <pre><code>.....
+.S-7.
+</code></pre>
+            </article>
+            <article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Again.</p>
+            <p>1. Not a list, 2) neither.</p><p>2) Nor this.</p><p>2023 is a year.</p><p>- Nor this.</p></article>"#,
+        );
+
+        assert!(md.contains(
+            "- `|` is a **vertical** pipe.\n- Nested:\n  - first\n  - second\n\n```text\n.....\n.S-7.\n```\n\n# --- Part Two ---\n\nAgain."
+        ));
+        assert!(md.contains(
+            "1\\. Not a list, 2) neither.\n\n2\\) Nor this.\n\n2023 is a year.\n\n\\- Nor this."
+        ));
+    }
+}
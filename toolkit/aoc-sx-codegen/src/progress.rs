@@ -0,0 +1,166 @@
+//! Editions progress, as shown in the README.
+
+use std::collections::BTreeMap;
+
+use aoc_sx_core::{
+    answers::AnswerStore,
+    calendar::{days_in_year, parts},
+    exercise::{ExerciseDay, ExerciseYear},
+};
+use aoc_sx_webclient::event::EventStars;
+
+use crate::Error;
+
+const START_MARKER: &str = "<!-- editions:start -->";
+const END_MARKER: &str = "<!-- editions:end -->";
+
+/// Progress of an edition.
+#[derive(Debug)]
+pub struct YearProgress {
+    pub year: ExerciseYear,
+    /// Stars of each day, from 1.
+    pub stars: BTreeMap<u8, u8>,
+}
+
+impl YearProgress {
+    /// Progress of an edition without any star.
+    pub fn empty(year: ExerciseYear) -> Self {
+        let stars = (1..=days_in_year(year.as_u16())).map(|d| (d, 0)).collect();
+        Self { year, stars }
+    }
+
+    /// Progress from known answers.
+    pub fn new(year: ExerciseYear, answers: &AnswerStore) -> Self {
+        let days = (1..=days_in_year(year.as_u16()))
+            .filter_map(|d| ExerciseDay::try_from(d).ok())
+            .collect::<Vec<_>>();
+
+        let mut stars = days
+            .iter()
+            .map(|day| {
+                let solved = parts(year, *day)
+                    .iter()
+                    .filter(|part| answers.is_solved(year, *day, **part))
+                    .count();
+                (day.as_u8(), solved as u8)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        // The last star is given once every other star is collected
+        let last_day = days_in_year(year.as_u16());
+        if stars.values().sum::<u8>() == last_day * 2 - 1 {
+            stars.insert(last_day, 2);
+        }
+
+        Self { year, stars }
+    }
+
+    /// Days with every part solved.
+    pub fn solved_days(&self) -> usize {
+        self.stars
+            .iter()
+            .filter_map(|(day, stars)| Some((ExerciseDay::try_from(*day).ok()?, *stars)))
+            .filter(|(day, stars)| usize::from(*stars) >= parts(self.year, *day).len())
+            .count()
+    }
+
+    /// Merge stars from the event page.
+    pub fn merge_stars(&mut self, event: &EventStars) {
+        for (day, stars) in self.stars.iter_mut() {
+            *stars = (*stars).max(event.stars(*day));
+        }
+    }
+
+    pub fn total_stars(&self) -> u32 {
+        self.stars.values().map(|s| u32::from(*s)).sum()
+    }
+}
+
+/// Render the editions section, optionally with a per-day grid.
+pub fn render_editions(progress: &[YearProgress], grid: bool) -> String {
+    let mut output = String::new();
+
+    for p in progress {
+        let days = days_in_year(p.year.as_u16());
+        output.push_str(&format!(
+            "- **{}** (**{}/{days}**, {}/{} stars)\n",
+            p.year,
+            p.solved_days(),
+            p.total_stars(),
+            u32::from(days) * 2
+        ));
+    }
+
+    if grid && !progress.is_empty() {
+        let max_days = progress
+            .iter()
+            .map(|p| days_in_year(p.year.as_u16()))
+            .max()
+            .unwrap_or(0);
+
+        output.push_str("\n| Year |");
+        for day in 1..=max_days {
+            output.push_str(&format!(" {day} |"));
+        }
+        output.push_str("\n|------|");
+        output.push_str(&"---|".repeat(max_days.into()));
+        output.push('\n');
+
+        for p in progress {
+            output.push_str(&format!("| {} |", p.year));
+            for day in 1..=max_days {
+                let cell = match p.stars.get(&day) {
+                    Some(2) => "★★",
+                    Some(1) => "★",
+                    Some(_) => "·",
+                    None => "",
+                };
+                output.push_str(&format!(" {cell} |"));
+            }
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/// Replace the content between the editions markers of a README.
+pub fn replace_editions_section(readme: &str, section: &str) -> Result<String, Error> {
+    let missing = || Error::NoEditionsMarkers(START_MARKER, END_MARKER);
+    let start = readme.find(START_MARKER).ok_or_else(missing)? + START_MARKER.len();
+    let end = readme[start..].find(END_MARKER).ok_or_else(missing)? + start;
+
+    Ok(format!(
+        "{}\n\n{section}\n{}",
+        &readme[..start],
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use aoc_sx_core::exercise::ExerciseYear;
+
+    use super::{render_editions, replace_editions_section, YearProgress};
+
+    #[test]
+    fn render_and_replace() {
+        let progress = YearProgress {
+            year: ExerciseYear::try_from(2020).unwrap(),
+            stars: BTreeMap::from([(1, 2), (2, 1), (3, 0)]),
+        };
+
+        let section = render_editions(&[progress], true);
+        assert!(section.starts_with("- **2020** (**1/25**, 3/50 stars)\n\n| Year | 1 | 2 | 3 |"));
+        assert!(section.contains("| 2020 | ★★ | ★ | · |"));
+
+        let readme = "# Title\n\n## Editions\n\n<!-- editions:start -->\nold\n<!-- editions:end -->\n\n## Toolkit\n";
+        assert_eq!(
+            replace_editions_section(readme, "- new\n").unwrap(),
+            "# Title\n\n## Editions\n\n<!-- editions:start -->\n\n- new\n\n<!-- editions:end -->\n\n## Toolkit\n"
+        );
+        assert!(replace_editions_section("# Title\n", "").is_err());
+    }
+}
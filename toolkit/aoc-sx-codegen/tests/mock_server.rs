@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use aoc_sx_codegen::{Error, ModuleGenerator, ModuleParameters};
+use aoc_sx_core::exercise::{ExerciseDay, ExerciseYear};
+use aoc_sx_mock::{fixtures_dir, Fixtures, MockServer};
+use aoc_sx_webclient::{throttle::Throttle, Client};
+use url::Url;
+
+const SESSION: &str = "mock-session";
+
+#[test]
+fn generate_module() {
+    let server = MockServer::start(Fixtures::new(fixtures_dir(), SESSION)).unwrap();
+    let client = Client::new(SESSION.into())
+        .with_base_url(Url::parse(server.url()).unwrap())
+        .with_throttle(Throttle::new(Duration::ZERO));
+
+    let dir = std::env::temp_dir().join(format!("aoc-sx-codegen-{}", std::process::id()));
+    let src = dir.join("src");
+    std::fs::create_dir_all(&src).unwrap();
+
+    let generator = ModuleGenerator::from_client(client).with_inputs_dir(dir.join("inputs"));
+    let parameters = || ModuleParameters {
+        year: ExerciseYear::try_from(2015).unwrap(),
+        day: ExerciseDay::try_from(1).unwrap(),
+    };
+    generator.generate_module(&src, parameters()).unwrap();
+
+    let read = |path: &str| std::fs::read_to_string(dir.join(path)).unwrap();
+    let mod_rs = read("src/day01/mod.rs");
+    assert!(mod_rs.starts_with("//! # --- Day 1: Mock Sums ---"));
+    assert!(mod_rs.contains("//! # --- Part Two ---"));
+    assert!(mod_rs.contains(&format!("<{}/2015/day/1/input>", server.url())));
+    assert!(read("src/day01/part1.rs").contains("indoc! {\"\n        1 2 3\"\n    };"));
+    assert!(read("src/lib.rs").contains("pub mod day01;"));
+    assert_eq!(read("inputs/2015/day01.txt"), "3 1 4 1 5\n");
+
+    // Refreshing the docs does not change an up-to-date module
+    let diff = generator.update_module_docs(&src, parameters()).unwrap();
+    assert!(diff.is_empty());
+
+    // Missing pages are reported, not unwrapped
+    let missing = ModuleParameters {
+        year: ExerciseYear::try_from(2015).unwrap(),
+        day: ExerciseDay::try_from(2).unwrap(),
+    };
+    assert!(matches!(
+        generator.generate_module(&src, missing),
+        Err(Error::Client { .. })
+    ));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,74 @@
+use std::{path::Path, time::Duration};
+
+use aoc_sx_codegen::{examples::DayExamples, parser::DayPageParser};
+use aoc_sx_core::exercise::{ExerciseDay, ExercisePart, ExerciseYear};
+use aoc_sx_mock::{local_recordings_dir, recording_names, recordings_dir};
+use aoc_sx_webclient::{throttle::Throttle, transport::Replay, Client};
+
+fn parse_day(year: u16, day: u8) -> (String, DayExamples) {
+    parse_recorded_day(&recordings_dir(), year, day)
+}
+
+fn parse_recorded_day(dir: &Path, year: u16, day: u8) -> (String, DayExamples) {
+    let page = Client::new("unused".into())
+        .with_transport(Replay::new(dir))
+        .with_throttle(Throttle::new(Duration::ZERO))
+        .fetch_exercise_page(
+            ExerciseYear::try_from(year).unwrap(),
+            ExerciseDay::try_from(day).unwrap(),
+        )
+        .unwrap();
+
+    let html = page.page_content.as_str();
+    let markdown = DayPageParser::new(page.page_url.clone(), page.input_url.clone()).parse(html);
+    (markdown.as_str().to_owned(), DayExamples::from_html(html))
+}
+
+#[test]
+fn day_page_with_both_parts() {
+    let (markdown, examples) = parse_day(2022, 1);
+    assert_eq!(markdown, include_str!("snapshots/2022-day-1.md"));
+
+    assert_eq!(examples.inputs(ExercisePart::First), ["100\n200\n\n300\n"]);
+    assert_eq!(examples.expected_answer(ExercisePart::First), Some("300"));
+    assert_eq!(examples.expected_answer(ExercisePart::Second), Some("600"));
+}
+
+#[test]
+fn day_page_with_first_part() {
+    let (markdown, examples) = parse_day(2024, 7);
+    assert_eq!(markdown, include_str!("snapshots/2024-day-7.md"));
+
+    assert_eq!(examples.expected_answer(ExercisePart::First), Some("3457"));
+    assert_eq!(examples.expected_answer(ExercisePart::Second), None);
+}
+
+#[test]
+fn local_day_pages() {
+    let Some(dir) = local_recordings_dir() else {
+        eprintln!("skipped: no local recordings");
+        return;
+    };
+
+    for name in recording_names(&dir).unwrap() {
+        let ["GET", year, "day", day] = name.iter().map(String::as_str).collect::<Vec<_>>()[..]
+        else {
+            continue;
+        };
+        let (year, day) = (year.parse().unwrap(), day.parse().unwrap());
+
+        let (markdown, examples) = parse_recorded_day(&dir, year, day);
+        assert!(
+            markdown.starts_with(&format!("# --- Day {day}: ")),
+            "{year} day {day}: no title in {markdown}"
+        );
+        assert!(
+            !markdown.contains("<article") && !markdown.contains("<p>"),
+            "{year} day {day}: unconverted markup in {markdown}"
+        );
+        assert!(
+            !examples.inputs(ExercisePart::First).is_empty(),
+            "{year} day {day}: no example"
+        );
+    }
+}
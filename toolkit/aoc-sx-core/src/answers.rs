@@ -0,0 +1,251 @@
+//! Known answers, keyed by year, day, part and input fingerprint.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    exercise::{Answer, ExerciseDay, ExercisePart, ExerciseYear},
+    input::workspace_root,
+};
+
+const ANSWERS_FILE_ENV: &str = "AOC_ANSWERS_FILE";
+const ANSWERS_FILE_NAME: &str = "answers.toml";
+const ANSWERS_FILE_HEADER: &str = "\
+# Known answers, keyed by year, day, part and input fingerprint.
+#
+# Entries without an `input` fingerprint were recorded before fingerprints:
+# a different result for them is reported as skipped, as the input may differ.
+";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Could not access answers file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not read answers file: {0}")]
+    Deserialize(#[from] toml::de::Error),
+
+    #[error("Could not write answers file: {0}")]
+    Serialize(#[from] toml::ser::Error),
+}
+
+/// Stored answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredAnswer {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+    pub answer: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct AnswersFile {
+    #[serde(default)]
+    answers: Vec<StoredAnswer>,
+}
+
+/// Known answer for an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KnownAnswer {
+    /// Recorded for this very input.
+    Exact(Answer),
+    /// Recorded without input fingerprint, maybe for another input.
+    Unverified(Answer),
+}
+
+/// Answers store, backed by a TOML file.
+#[derive(Debug)]
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: Vec<StoredAnswer>,
+}
+
+impl AnswerStore {
+    /// Load the store from the `AOC_ANSWERS_FILE` variable, or `answers.toml` at the workspace root.
+    pub fn from_env() -> Result<Self, Error> {
+        Self::load(default_answers_path())
+    }
+
+    /// Load the store from a file, which may not exist yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let file: AnswersFile = if path.exists() {
+            toml::from_str(&std::fs::read_to_string(&path)?)?
+        } else {
+            AnswersFile::default()
+        };
+
+        Ok(Self {
+            path,
+            answers: file.answers,
+        })
+    }
+
+    /// Save the store to its file.
+    pub fn save(&self) -> Result<(), Error> {
+        let file = AnswersFile {
+            answers: self.answers.clone(),
+        };
+
+        let content = format!("{ANSWERS_FILE_HEADER}\n{}", toml::to_string(&file)?);
+        std::fs::write(&self.path, content)?;
+        Ok(())
+    }
+
+    /// Get the known answer for a part and input, falling back to an answer recorded without fingerprint.
+    pub fn get(
+        &self,
+        year: ExerciseYear,
+        day: ExerciseDay,
+        part: ExercisePart,
+        input: &str,
+    ) -> Option<KnownAnswer> {
+        let fingerprint = fingerprint(input);
+        let candidates = self
+            .answers
+            .iter()
+            .filter(|a| a.year == year.as_u16() && a.day == day.as_u8() && a.part == part.as_u8());
+
+        let mut unverified = None;
+        for entry in candidates {
+            match &entry.input {
+                Some(input) if *input == fingerprint => {
+                    return entry.answer.parse().ok().map(KnownAnswer::Exact)
+                }
+                Some(_) => {}
+                None => unverified = unverified.or(Some(entry)),
+            }
+        }
+
+        unverified
+            .and_then(|entry| entry.answer.parse().ok())
+            .map(KnownAnswer::Unverified)
+    }
+
+    /// Whether an answer is known for a part, whatever the input.
+    pub fn is_solved(&self, year: ExerciseYear, day: ExerciseDay, part: ExercisePart) -> bool {
+        self.answers
+            .iter()
+            .any(|a| a.year == year.as_u16() && a.day == day.as_u8() && a.part == part.as_u8())
+    }
+
+    /// Record an answer for a part and input, replacing any previous answer for this input.
+    pub fn record(
+        &mut self,
+        year: ExerciseYear,
+        day: ExerciseDay,
+        part: ExercisePart,
+        input: &str,
+        answer: &Answer,
+    ) {
+        let entry = StoredAnswer {
+            year: year.as_u16(),
+            day: day.as_u8(),
+            part: part.as_u8(),
+            input: Some(fingerprint(input)),
+            answer: answer.to_string(),
+        };
+
+        self.answers.retain(|a| {
+            !(a.year == entry.year
+                && a.day == entry.day
+                && a.part == entry.part
+                && a.input == entry.input)
+        });
+        self.answers.push(entry);
+        self.answers.sort_by(|a, b| {
+            (a.year, a.day, a.part, &a.input).cmp(&(b.year, b.day, b.part, &b.input))
+        });
+    }
+}
+
+/// Answers file from the `AOC_ANSWERS_FILE` variable, or `answers.toml` at the workspace root.
+pub fn default_answers_path() -> PathBuf {
+    std::env::var_os(ANSWERS_FILE_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_root().join(ANSWERS_FILE_NAME))
+}
+
+/// Stable fingerprint of a puzzle input (FNV-1a, ignoring trailing whitespace).
+pub fn fingerprint(input: &str) -> String {
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::exercise::{Answer, ExerciseDay, ExercisePart, ExerciseYear};
+
+    use super::{fingerprint, AnswerStore, KnownAnswer, StoredAnswer};
+
+    fn store(answers: Vec<StoredAnswer>) -> AnswerStore {
+        AnswerStore {
+            path: "answers.toml".into(),
+            answers,
+        }
+    }
+
+    fn entry(input: Option<&str>, answer: &str) -> StoredAnswer {
+        StoredAnswer {
+            year: 2020,
+            day: 1,
+            part: 1,
+            input: input.map(fingerprint),
+            answer: answer.into(),
+        }
+    }
+
+    #[test]
+    fn fingerprint_ignores_trailing_whitespace() {
+        assert_eq!(fingerprint("1721\n979\n"), fingerprint("1721\n979"));
+        assert_ne!(fingerprint("1721\n979"), fingerprint("979\n1721"));
+    }
+
+    #[test]
+    fn get_prefers_fingerprinted_answers() {
+        let year = ExerciseYear::try_from(2020).unwrap();
+        let day = ExerciseDay::try_from(1).unwrap();
+        let part = ExercisePart::First;
+
+        let legacy = KnownAnswer::Unverified(Answer::Integer(514_579));
+        assert_eq!(
+            store(vec![entry(None, "514579")]).get(year, day, part, "a"),
+            Some(legacy.clone())
+        );
+
+        // The legacy answer still applies to other inputs than the fingerprinted one
+        let mixed = store(vec![entry(None, "514579"), entry(Some("a"), "42")]);
+        assert_eq!(
+            mixed.get(year, day, part, "a"),
+            Some(KnownAnswer::Exact(Answer::Integer(42)))
+        );
+        assert_eq!(mixed.get(year, day, part, "b"), Some(legacy));
+
+        let fingerprinted = store(vec![entry(Some("a"), "42")]);
+        assert_eq!(fingerprinted.get(year, day, part, "b"), None);
+    }
+
+    #[test]
+    fn record_replaces_previous_answer() {
+        let year = ExerciseYear::try_from(2020).unwrap();
+        let day = ExerciseDay::try_from(1).unwrap();
+        let part = ExercisePart::First;
+
+        let mut store = store(vec![entry(Some("a"), "41")]);
+        store.record(year, day, part, "a", &Answer::from(42_usize));
+        assert_eq!(store.answers.len(), 1);
+        assert_eq!(
+            store.get(year, day, part, "a"),
+            Some(KnownAnswer::Exact(Answer::Integer(42)))
+        );
+    }
+}
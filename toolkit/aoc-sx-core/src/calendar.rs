@@ -0,0 +1,433 @@
+//! Advent calendar: years, days, unlock instants and parts.
+
+use std::{
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::exercise::{ExerciseDay, ExercisePart, ExerciseYear};
+
+/// First Advent of Code event.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// First event with 12 days instead of 25.
+const SHORT_EVENTS_YEAR: u16 = 2025;
+
+/// Puzzles unlock at midnight US Eastern (UTC-5 in December).
+const EASTERN_OFFSET: Duration = Duration::from_secs(5 * 3600);
+
+const SECONDS_PER_DAY: u64 = 86400;
+
+/// Interval between two countdown updates.
+const COUNTDOWN_TICK: Duration = Duration::from_secs(1);
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Year {0} has not started yet.")]
+    YearNotStarted(u16),
+
+    #[error("Day {day} does not exist in {year}, which has {days} days.")]
+    DayOutOfRange { year: u16, day: u8, days: u8 },
+
+    #[error("Day {day} of {year} is not unlocked yet, {}s left.", .remaining.as_secs())]
+    Locked {
+        year: u16,
+        day: u8,
+        remaining: Duration,
+    },
+
+    #[error("Day {day} of {year} has no part {part}.")]
+    NoSuchPart { year: u16, day: u8, part: u8 },
+}
+
+/// Source of the current instant.
+pub trait Clock: std::fmt::Debug {
+    fn now(&self) -> SystemTime;
+
+    /// Wait for a duration.
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// System clock.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock stuck at an instant.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub SystemTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+/// Clock only moving forward when sleeping, without actually waiting.
+#[derive(Debug)]
+pub struct SimulatedClock(Mutex<SystemTime>);
+
+impl SimulatedClock {
+    pub fn new(start: SystemTime) -> Self {
+        Self(Mutex::new(start))
+    }
+}
+
+impl Clock for SimulatedClock {
+    fn now(&self) -> SystemTime {
+        *self.0.lock().unwrap()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        *self.0.lock().unwrap() += duration;
+    }
+}
+
+/// Number of days of an event.
+pub fn days_in_year(year: u16) -> u8 {
+    if year >= SHORT_EVENTS_YEAR {
+        12
+    } else {
+        25
+    }
+}
+
+/// Parts of a day: the last day of an event only has a first part.
+pub fn parts(year: ExerciseYear, day: ExerciseDay) -> &'static [ExercisePart] {
+    if day.as_u8() == days_in_year(year.as_u16()) {
+        &[ExercisePart::First]
+    } else {
+        &[ExercisePart::First, ExercisePart::Second]
+    }
+}
+
+/// Unlock instant of a day.
+pub fn unlock_time(year: ExerciseYear, day: ExerciseDay) -> SystemTime {
+    let days = days_from_civil(year.as_u16().into(), 12, day.as_u8().into());
+    UNIX_EPOCH + Duration::from_secs(days * SECONDS_PER_DAY) + EASTERN_OFFSET
+}
+
+/// Year of an instant, in US Eastern time.
+pub fn eastern_year(instant: SystemTime) -> u16 {
+    let secs = instant
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.saturating_sub(EASTERN_OFFSET).as_secs())
+        .unwrap_or(0);
+
+    civil_year_from_days(secs / SECONDS_PER_DAY)
+}
+
+/// Calendar, as seen from a clock.
+#[derive(Debug, Default)]
+pub struct Calendar<C: Clock = SystemClock> {
+    clock: C,
+}
+
+impl Calendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: Clock> Calendar<C> {
+    /// Use a specific clock.
+    pub fn with_clock(clock: C) -> Self {
+        Self { clock }
+    }
+
+    pub fn now(&self) -> SystemTime {
+        self.clock.now()
+    }
+
+    /// Wait for a duration, on the calendar clock.
+    pub fn sleep(&self, duration: Duration) {
+        self.clock.sleep(duration);
+    }
+
+    /// Last year with at least one unlocked day.
+    pub fn latest_year(&self) -> u16 {
+        let year = eastern_year(self.now());
+        match ExerciseYear::try_from(year) {
+            Ok(y) if self.is_unlocked(y, first_day()) => year,
+            _ => year - 1,
+        }
+    }
+
+    /// Years with at least one unlocked day.
+    pub fn years(&self) -> Vec<ExerciseYear> {
+        (FIRST_YEAR..=self.latest_year())
+            .filter_map(|y| ExerciseYear::try_from(y).ok())
+            .collect()
+    }
+
+    pub fn is_unlocked(&self, year: ExerciseYear, day: ExerciseDay) -> bool {
+        self.now() >= unlock_time(year, day)
+    }
+
+    /// Time left before a day unlocks, zero if already unlocked.
+    pub fn time_until_unlock(&self, year: ExerciseYear, day: ExerciseDay) -> Duration {
+        unlock_time(year, day)
+            .duration_since(self.now())
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Wait until a day unlocks, calling `tick` with the time left about every second.
+    pub fn wait_for_unlock(
+        &self,
+        year: ExerciseYear,
+        day: ExerciseDay,
+        mut tick: impl FnMut(Duration),
+    ) {
+        loop {
+            let remaining = self.time_until_unlock(year, day);
+            if remaining.is_zero() {
+                return;
+            }
+
+            tick(remaining);
+            self.sleep(remaining.min(COUNTDOWN_TICK));
+        }
+    }
+
+    /// Next day to unlock in the event of the current year, if any.
+    pub fn next_day(&self) -> Option<(ExerciseYear, ExerciseDay)> {
+        let year = ExerciseYear::try_from(eastern_year(self.now())).ok()?;
+        self.days(year)
+            .into_iter()
+            .find(|d| !self.is_unlocked(year, *d))
+            .map(|d| (year, d))
+    }
+
+    /// Days of a year, unlocked or not.
+    pub fn days(&self, year: ExerciseYear) -> Vec<ExerciseDay> {
+        (1..=days_in_year(year.as_u16()))
+            .filter_map(|d| ExerciseDay::try_from(d).ok())
+            .collect()
+    }
+
+    /// Unlocked days of a year.
+    pub fn unlocked_days(&self, year: ExerciseYear) -> Vec<ExerciseDay> {
+        self.days(year)
+            .into_iter()
+            .filter(|d| self.is_unlocked(year, *d))
+            .collect()
+    }
+
+    /// Check that a year has started.
+    pub fn check_year(&self, year: ExerciseYear) -> Result<(), Error> {
+        if year.as_u16() > self.latest_year() {
+            return Err(Error::YearNotStarted(year.as_u16()));
+        }
+
+        Ok(())
+    }
+
+    /// Check that a year is not after the current one, whose event may not have started yet.
+    pub fn check_year_exists(&self, year: ExerciseYear) -> Result<(), Error> {
+        if year.as_u16() > eastern_year(self.now()) {
+            return Err(Error::YearNotStarted(year.as_u16()));
+        }
+
+        Ok(())
+    }
+
+    /// Check that a day exists in its year.
+    pub fn check_day_exists(&self, year: ExerciseYear, day: ExerciseDay) -> Result<(), Error> {
+        let days = days_in_year(year.as_u16());
+        if day.as_u8() > days {
+            return Err(Error::DayOutOfRange {
+                year: year.as_u16(),
+                day: day.as_u8(),
+                days,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Check that a day exists and is unlocked.
+    pub fn check_day(&self, year: ExerciseYear, day: ExerciseDay) -> Result<(), Error> {
+        self.check_day_exists(year, day)?;
+
+        if !self.is_unlocked(year, day) {
+            return Err(Error::Locked {
+                year: year.as_u16(),
+                day: day.as_u8(),
+                remaining: self.time_until_unlock(year, day),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Check that a day is unlocked and has a part.
+    pub fn check_part(
+        &self,
+        year: ExerciseYear,
+        day: ExerciseDay,
+        part: ExercisePart,
+    ) -> Result<(), Error> {
+        self.check_day(year, day)?;
+
+        if !parts(year, day).contains(&part) {
+            return Err(Error::NoSuchPart {
+                year: year.as_u16(),
+                day: day.as_u8(),
+                part: part.as_u8(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+fn first_day() -> ExerciseDay {
+    ExerciseDay::try_from(1).unwrap()
+}
+
+/// Days since the unix epoch of a date, for dates after it.
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Year of a number of days since the unix epoch.
+fn civil_year_from_days(days: u64) -> u16 {
+    let days = days + 719468;
+    let era = days / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let year = year_of_era + era * 400;
+
+    // Months are counted from March
+    (if month_index >= 10 { year + 1 } else { year }) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::exercise::{ExerciseDay, ExercisePart, ExerciseYear};
+
+    use super::{eastern_year, parts, unlock_time, Calendar, Error, FixedClock, SimulatedClock};
+
+    fn year(y: u16) -> ExerciseYear {
+        ExerciseYear::try_from(y).unwrap()
+    }
+
+    fn day(d: u8) -> ExerciseDay {
+        ExerciseDay::try_from(d).unwrap()
+    }
+
+    #[test]
+    fn unlock_at_midnight_eastern() {
+        // 2023-12-01T05:00:00Z
+        assert_eq!(
+            unlock_time(year(2023), day(1)),
+            UNIX_EPOCH + Duration::from_secs(1701406800)
+        );
+        // 2024-12-25T05:00:00Z
+        assert_eq!(
+            unlock_time(year(2024), day(25)),
+            UNIX_EPOCH + Duration::from_secs(1735102800)
+        );
+    }
+
+    #[test]
+    fn years_follow_eastern_time() {
+        // 2024-01-01T04:59:59Z is still 2023 in US Eastern
+        assert_eq!(
+            eastern_year(UNIX_EPOCH + Duration::from_secs(1704085199)),
+            2023
+        );
+        assert_eq!(
+            eastern_year(UNIX_EPOCH + Duration::from_secs(1704085200)),
+            2024
+        );
+    }
+
+    #[test]
+    fn unlocked_days() {
+        // One second before 2023-12-02T05:00:00Z
+        let calendar =
+            Calendar::with_clock(FixedClock(UNIX_EPOCH + Duration::from_secs(1701493200 - 1)));
+
+        assert_eq!(calendar.latest_year(), 2023);
+        assert_eq!(calendar.unlocked_days(year(2023)), vec![day(1)]);
+        assert_eq!(
+            calendar.time_until_unlock(year(2023), day(2)),
+            Duration::from_secs(1)
+        );
+        assert!(matches!(
+            calendar.check_day(year(2023), day(2)),
+            Err(Error::Locked { .. })
+        ));
+
+        // Before December, the latest event is the previous one
+        let calendar =
+            Calendar::with_clock(FixedClock(UNIX_EPOCH + Duration::from_secs(1701406800 - 1)));
+        assert_eq!(calendar.latest_year(), 2022);
+        assert_eq!(calendar.years().len(), 8);
+        assert!(calendar.check_year(year(2023)).is_err());
+        assert!(calendar.check_year_exists(year(2023)).is_ok());
+        assert!(matches!(
+            calendar.check_year_exists(year(2024)),
+            Err(Error::YearNotStarted(2024))
+        ));
+    }
+
+    #[test]
+    fn days_and_parts() {
+        let calendar =
+            Calendar::with_clock(FixedClock(UNIX_EPOCH + Duration::from_secs(1800000000)));
+
+        assert_eq!(parts(year(2024), day(25)), [ExercisePart::First]);
+        assert_eq!(parts(year(2024), day(12)).len(), 2);
+        assert_eq!(parts(year(2025), day(12)), [ExercisePart::First]);
+
+        assert!(calendar.check_day(year(2024), day(25)).is_ok());
+        assert!(matches!(
+            calendar.check_day(year(2025), day(13)),
+            Err(Error::DayOutOfRange { days: 12, .. })
+        ));
+        assert!(matches!(
+            calendar.check_part(year(2024), day(25), ExercisePart::Second),
+            Err(Error::NoSuchPart { .. })
+        ));
+    }
+
+    #[test]
+    fn wait_for_unlock() {
+        // 2023-12-02T05:00:00Z
+        let unlock = UNIX_EPOCH + Duration::from_secs(1701493200);
+        let calendar =
+            Calendar::with_clock(SimulatedClock::new(unlock - Duration::from_millis(2500)));
+        assert_eq!(calendar.next_day(), Some((year(2023), day(2))));
+
+        let mut ticks = vec![];
+        calendar.wait_for_unlock(year(2023), day(2), |remaining| ticks.push(remaining));
+        assert_eq!(ticks, [2500, 1500, 500].map(Duration::from_millis));
+        assert_eq!(calendar.now(), unlock);
+        assert_eq!(calendar.next_day(), Some((year(2023), day(3))));
+
+        // 2024-01-01T00:00:00Z, every day of 2023 is unlocked
+        let calendar =
+            Calendar::with_clock(FixedClock(UNIX_EPOCH + Duration::from_secs(1704067200)));
+        assert_eq!(calendar.next_day(), None);
+    }
+}
@@ -1,7 +1,9 @@
 use std::{fmt::Display, str::FromStr};
 
-const MIN_YEAR: u16 = 2015;
-const MAX_YEAR: u16 = 2024;
+use crate::calendar::FIRST_YEAR;
+
+/// Longest event, in days.
+const MAX_DAY: u8 = 25;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -15,7 +17,7 @@ pub enum Error {
     InvalidPart(String),
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ExerciseYear(u16);
 
 impl Display for ExerciseYear {
@@ -28,7 +30,8 @@ impl TryFrom<u16> for ExerciseYear {
     type Error = Error;
 
     fn try_from(value: u16) -> Result<Self, Self::Error> {
-        if !(MIN_YEAR..=MAX_YEAR).contains(&value) {
+        // Whether the event has started is up to the calendar
+        if value < FIRST_YEAR {
             Err(Error::InvalidYear(value))
         } else {
             Ok(Self(value))
@@ -36,6 +39,12 @@ impl TryFrom<u16> for ExerciseYear {
     }
 }
 
+impl ExerciseYear {
+    pub fn as_u16(&self) -> u16 {
+        self.0
+    }
+}
+
 impl FromStr for ExerciseYear {
     type Err = Error;
 
@@ -54,7 +63,7 @@ impl FromStr for ExerciseDay {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ExerciseDay(u8);
 
 impl Display for ExerciseDay {
@@ -67,7 +76,7 @@ impl TryFrom<u8> for ExerciseDay {
     type Error = Error;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        if value > 25 {
+        if !(1..=MAX_DAY).contains(&value) {
             Err(Error::InvalidDay(value))
         } else {
             Ok(Self(value))
@@ -81,7 +90,7 @@ impl ExerciseDay {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ExercisePart {
     First,
     Second,
@@ -94,6 +103,13 @@ impl ExercisePart {
             Self::Second => "2",
         }
     }
+
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            Self::First => 1,
+            Self::Second => 2,
+        }
+    }
 }
 
 impl TryFrom<&str> for ExercisePart {
@@ -115,3 +131,139 @@ impl FromStr for ExercisePart {
         Self::try_from(s)
     }
 }
+
+/// Answer to an exercise part.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Integer(value) => f.write_fmt(format_args!("{value}")),
+            Self::Text(value) => f.write_str(value),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        // Only canonical integers, e.g. "01029498" keeps its leading zero
+        Ok(match s.parse::<i128>() {
+            Ok(value) if value.to_string() == s => Self::Integer(value),
+            _ => Self::Text(s.to_owned()),
+        })
+    }
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Self::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Same as parsing, so that numeric strings equal their integer.
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        let Ok(answer) = value.parse();
+        answer
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        // Both sides rendered, as parsing does
+        match self {
+            Self::Integer(value) => value.to_string() == other.trim(),
+            Self::Text(value) => value == other.trim(),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, ExerciseYear};
+
+    #[test]
+    fn years_from_first_event() {
+        assert!(ExerciseYear::try_from(2014).is_err());
+        assert_eq!(ExerciseYear::try_from(2031).unwrap().as_u16(), 2031);
+    }
+
+    #[test]
+    fn answer_from_integers() {
+        assert_eq!(Answer::from(u64::MAX), Answer::Integer(u64::MAX as i128));
+        assert_eq!(Answer::from(-12_i32), Answer::Integer(-12));
+        assert_eq!(
+            Answer::from(3_454_189_699_072_usize).to_string(),
+            "3454189699072"
+        );
+    }
+
+    #[test]
+    fn answer_from_text() {
+        assert_eq!(Answer::from("JPZCUAUR").to_string(), "JPZCUAUR");
+        assert_eq!(
+            "27865934".parse::<Answer>().unwrap(),
+            Answer::Integer(27_865_934)
+        );
+        assert_eq!(
+            "LJSVLTWQM".parse::<Answer>().unwrap(),
+            Answer::from("LJSVLTWQM")
+        );
+    }
+
+    #[test]
+    fn answer_eq_stored() {
+        assert_eq!(Answer::from(987_339_usize), "987339");
+        assert_eq!(Answer::from(-5_i32), " -5\n");
+        assert_eq!(Answer::from("JPZCUAUR"), "JPZCUAUR".to_string());
+        assert_ne!(Answer::from(1_u32), "one");
+    }
+
+    #[test]
+    fn answer_normalized() {
+        assert_eq!(Answer::from("123"), Answer::from(123_u32));
+        assert_eq!(
+            Answer::from("67384529".to_string()),
+            Answer::Integer(67_384_529)
+        );
+
+        // Leading zeros are part of the answer
+        let answer = "01029498".parse::<Answer>().unwrap();
+        assert_eq!(answer, Answer::Text("01029498".into()));
+        assert_eq!(answer.to_string(), "01029498");
+        assert_ne!(Answer::from(1_029_498_u32), "01029498");
+    }
+}
@@ -0,0 +1,90 @@
+//! Puzzle inputs.
+
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use crate::exercise::{ExerciseDay, ExerciseYear};
+
+const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+const INPUTS_DIR_NAME: &str = "inputs";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Missing input file {0:?}.")]
+    MissingInput(PathBuf),
+
+    #[error("Could not read input: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    /// A specific file.
+    File(PathBuf),
+    /// An inputs directory, organized as `{year}/day{day:02}.txt`, e.g. `2020/day01.txt`.
+    Directory(PathBuf),
+    /// Standard input.
+    Stdin,
+    /// An explicit string.
+    Text(String),
+}
+
+impl InputSource {
+    /// Inputs directory from the `AOC_INPUTS_DIR` variable, or the `inputs` folder at the workspace root.
+    pub fn from_env() -> Self {
+        Self::Directory(default_inputs_dir())
+    }
+
+    /// Load the input for a specific day.
+    pub fn load(&self, year: ExerciseYear, day: ExerciseDay) -> Result<String, Error> {
+        match self {
+            Self::File(path) => read_file(path),
+            Self::Directory(dir) => read_file(&input_path(dir, year, day)),
+            Self::Stdin => {
+                let mut buffer = String::new();
+                std::io::stdin().read_to_string(&mut buffer)?;
+                Ok(buffer)
+            }
+            Self::Text(text) => Ok(text.clone()),
+        }
+    }
+}
+
+/// Path of a day input inside an inputs directory.
+pub fn input_path(dir: &Path, year: ExerciseYear, day: ExerciseDay) -> PathBuf {
+    dir.join(year.to_string())
+        .join(format!("day{:02}.txt", day.as_u8()))
+}
+
+/// Inputs directory from the `AOC_INPUTS_DIR` variable, or the `inputs` folder at the workspace root.
+pub fn default_inputs_dir() -> PathBuf {
+    std::env::var_os(INPUTS_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| workspace_root().join(INPUTS_DIR_NAME))
+}
+
+/// Find the workspace root from the current directory, falling back to the current directory.
+pub fn workspace_root() -> PathBuf {
+    let current_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+
+    current_dir
+        .ancestors()
+        .find(|path| {
+            std::fs::read_to_string(path.join("Cargo.toml"))
+                .map(|content| content.contains("[workspace]"))
+                .unwrap_or(false)
+        })
+        .map(Path::to_path_buf)
+        .unwrap_or(current_dir)
+}
+
+fn read_file(path: &Path) -> Result<String, Error> {
+    if !path.exists() {
+        return Err(Error::MissingInput(path.to_path_buf()));
+    }
+
+    Ok(std::fs::read_to_string(path)?)
+}
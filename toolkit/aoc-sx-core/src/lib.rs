@@ -1 +1,6 @@
+pub mod answers;
+pub mod calendar;
 pub mod exercise;
+pub mod input;
+pub mod solution;
+pub mod testing;
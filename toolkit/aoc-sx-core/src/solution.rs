@@ -0,0 +1,129 @@
+//! Solutions and their registry.
+
+use std::marker::PhantomData;
+
+use crate::exercise::{Answer, ExerciseDay, ExercisePart, ExerciseYear};
+
+/// A solved exercise day.
+pub trait Solution {
+    /// Exercise year.
+    const YEAR: u16;
+    /// Exercise day.
+    const DAY: u8;
+
+    /// Puzzle input given to both parts, which may borrow the raw input.
+    type Input<'a>;
+
+    /// Prepare the puzzle input for both parts.
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    /// Solve the first part.
+    fn part1(input: &Self::Input<'_>) -> impl Into<Answer>;
+
+    /// Solve the second part, if there is one.
+    fn part2(input: &Self::Input<'_>) -> Option<impl Into<Answer>>;
+}
+
+/// Type-erased solution, as stored in a [`Registry`].
+pub trait RegisteredSolution: Sync {
+    /// Exercise year.
+    fn year(&self) -> ExerciseYear;
+
+    /// Exercise day.
+    fn day(&self) -> ExerciseDay;
+
+    /// Prepare the puzzle input.
+    fn parse<'a>(&self, input: &'a str) -> Box<dyn ParsedInput + 'a>;
+}
+
+/// Prepared puzzle input, ready to be solved.
+pub trait ParsedInput {
+    /// Solve a part, returning `None` if the part does not exist.
+    fn solve(&self, part: ExercisePart) -> Option<Answer>;
+}
+
+struct Parsed<'a, S: Solution>(S::Input<'a>, PhantomData<S>);
+
+impl<S: Solution> ParsedInput for Parsed<'_, S> {
+    fn solve(&self, part: ExercisePart) -> Option<Answer> {
+        match part {
+            ExercisePart::First => Some(S::part1(&self.0).into()),
+            ExercisePart::Second => S::part2(&self.0).map(Into::into),
+        }
+    }
+}
+
+impl<S> RegisteredSolution for S
+where
+    S: Solution + Sync + 'static,
+{
+    fn year(&self) -> ExerciseYear {
+        ExerciseYear::try_from(S::YEAR).expect("Solution year should be valid.")
+    }
+
+    fn day(&self) -> ExerciseDay {
+        ExerciseDay::try_from(S::DAY).expect("Solution day should be valid.")
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Box<dyn ParsedInput + 'a> {
+        Box::new(Parsed::<S>(S::parse(input), PhantomData))
+    }
+}
+
+/// Collection of every registered solution.
+#[derive(Default)]
+pub struct Registry {
+    solutions: Vec<&'static dyn RegisteredSolution>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a solution.
+    pub fn register(&mut self, solution: &'static dyn RegisteredSolution) {
+        self.solutions.push(solution);
+        self.solutions.sort_by_key(|s| (s.year(), s.day()));
+    }
+
+    /// Get the solution for a specific day.
+    pub fn get(
+        &self,
+        year: ExerciseYear,
+        day: ExerciseDay,
+    ) -> Option<&'static dyn RegisteredSolution> {
+        self.solutions
+            .iter()
+            .find(|s| s.year() == year && s.day() == day)
+            .copied()
+    }
+
+    /// Iterate over every solution, sorted by year and day.
+    pub fn solutions(&self) -> impl Iterator<Item = &'static dyn RegisteredSolution> + '_ {
+        self.solutions.iter().copied()
+    }
+
+    /// Iterate over the solutions of a year, sorted by day.
+    pub fn solutions_for_year(
+        &self,
+        year: ExerciseYear,
+    ) -> impl Iterator<Item = &'static dyn RegisteredSolution> + '_ {
+        self.solutions().filter(move |s| s.year() == year)
+    }
+
+    /// Years having at least one solution.
+    pub fn years(&self) -> Vec<ExerciseYear> {
+        let mut years = self.solutions().map(|s| s.year()).collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+}
+
+impl std::fmt::Debug for Registry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.solutions().map(|s| (s.year(), s.day())))
+            .finish()
+    }
+}
@@ -0,0 +1,55 @@
+//! Test helpers.
+
+use crate::{
+    answers::{AnswerStore, KnownAnswer},
+    exercise::{Answer, ExerciseDay, ExercisePart, ExerciseYear},
+    input::{Error, InputSource},
+    solution::Solution,
+};
+
+/// Load the real puzzle input of a solution, or report the test as skipped if it is missing.
+pub fn test_input<S: Solution>() -> Option<String> {
+    let (year, day) = solution_day::<S>();
+
+    match InputSource::from_env().load(year, day) {
+        Ok(input) => Some(input),
+        Err(Error::MissingInput(path)) => {
+            eprintln!("skipped: missing input for {year} day {day} ({path:?})");
+            None
+        }
+        Err(e) => panic!("Could not load input for {year} day {day}: {e}"),
+    }
+}
+
+/// Check a part result against the answers store, or report the test as skipped if no answer is known for this input.
+#[track_caller]
+pub fn check_answer<S: Solution>(part: ExercisePart, input: &str, result: impl Into<Answer>) {
+    let (year, day) = solution_day::<S>();
+    let store = AnswerStore::from_env().expect("Could not load answers store.");
+
+    let result = result.into();
+    match store.get(year, day, part, input) {
+        Some(KnownAnswer::Exact(expected)) => assert_eq!(
+            result,
+            expected,
+            "Wrong answer for {year} day {day} part {}",
+            part.as_u8()
+        ),
+        Some(KnownAnswer::Unverified(expected)) if result != expected => eprintln!(
+            "skipped: {year} day {day} part {} differs from an answer recorded without input fingerprint",
+            part.as_u8()
+        ),
+        Some(KnownAnswer::Unverified(_)) => {}
+        None => eprintln!(
+            "skipped: no known answer for {year} day {day} part {}",
+            part.as_u8()
+        ),
+    }
+}
+
+fn solution_day<S: Solution>() -> (ExerciseYear, ExerciseDay) {
+    (
+        ExerciseYear::try_from(S::YEAR).expect("Solution year should be valid."),
+        ExerciseDay::try_from(S::DAY).expect("Solution day should be valid."),
+    )
+}
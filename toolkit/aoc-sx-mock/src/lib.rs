@@ -0,0 +1,440 @@
+//! Local Advent of Code server, serving fixture files to test the toolkit without network access.
+//!
+//! Fixtures follow the cache layout:
+//!
+//! - `{year}/event.html`: content of the event page
+//! - `{year}/day{day:02}/page.html`: content of a day page
+//! - `{year}/day{day:02}/input.txt`: puzzle input
+//! - `{year}/day{day:02}/answers.txt`: right answers, one line per part
+//! - `{year}/leaderboards/{id}.json`: private leaderboard
+//!
+//! Responses in the format of `aoc_sx_webclient::transport::Recorder` are also bundled, to be replayed.
+//! They are synthetic: hand-written after the markup of real pages (`main`, `article.day-desc`,
+//! answer `article`, `div.user`), with made-up puzzle text, as the puzzle text of the event may
+//! not be redistributed.
+//!
+//! Real pages can still be checked locally, from recordings kept out of git:
+//!
+//! 1. Set `record_dir = "recordings"` in `aoc-sx.toml`, and fetch pages or send answers with an
+//!    empty cache, e.g. `just tk sync --year 2022 --cache-dir /tmp/aoc-sx-cache`.
+//! 2. Run `cargo test --test replay`: the replay tests also go through every recording of
+//!    `recordings/` at the workspace root (or `AOC_RECORDINGS_DIR`).
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::JoinHandle,
+};
+
+/// Account name shown in the page headers.
+pub const ACCOUNT_NAME: &str = "Mock User";
+
+const LOCAL_RECORDINGS_ENV: &str = "AOC_RECORDINGS_DIR";
+
+const LOGGED_OUT_INPUT: &str =
+    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+
+/// Fixtures bundled with this crate.
+pub fn fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures")
+}
+
+/// Synthetic recorded responses bundled with this crate.
+pub fn recordings_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("recordings")
+}
+
+/// Local recordings of real pages: `AOC_RECORDINGS_DIR`, or `recordings` at the workspace root.
+///
+/// Returns `None` if the folder does not exist, e.g. in a fresh clone.
+pub fn local_recordings_dir() -> Option<PathBuf> {
+    let dir = std::env::var_os(LOCAL_RECORDINGS_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../recordings"));
+
+    dir.is_dir().then_some(dir)
+}
+
+/// Names of the recordings of a folder, split on `-`, e.g. `["GET", "2022", "day", "1"]`.
+pub fn recording_names(dir: &Path) -> std::io::Result<Vec<Vec<String>>> {
+    let mut names = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if let Some(name) = path
+            .extension()
+            .is_some_and(|e| e == "json")
+            .then(|| path.file_stem().and_then(|s| s.to_str()))
+            .flatten()
+        {
+            names.push(name.split('-').map(str::to_owned).collect());
+        }
+    }
+
+    names.sort();
+    Ok(names)
+}
+
+/// Received request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub session: Option<String>,
+    pub body: String,
+}
+
+/// Response to send.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn html(status: u16, main: &str, logged_in: bool) -> Self {
+        Self {
+            status,
+            content_type: "text/html",
+            body: layout(main, logged_in),
+        }
+    }
+
+    fn text(status: u16, body: String) -> Self {
+        Self {
+            status,
+            content_type: "text/plain",
+            body,
+        }
+    }
+
+    fn json(body: String) -> Self {
+        Self {
+            status: 200,
+            content_type: "application/json",
+            body,
+        }
+    }
+
+    fn not_found(logged_in: bool) -> Self {
+        Self::html(404, "<article><p>404 Not Found</p></article>", logged_in)
+    }
+}
+
+/// Routes requests to fixture files.
+#[derive(Debug, Clone)]
+pub struct Fixtures {
+    dir: PathBuf,
+    session: String,
+}
+
+impl Fixtures {
+    /// Serve a fixtures folder to clients using a specific session token.
+    pub fn new<P: AsRef<Path>>(dir: P, session: &str) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+            session: session.to_owned(),
+        }
+    }
+
+    /// Answer a request.
+    pub fn handle(&self, request: &Request) -> Response {
+        let logged_in = request.session.as_deref() == Some(&self.session[..]);
+        let segments = request
+            .path
+            .split('?')
+            .next()
+            .unwrap_or_default()
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+
+        match (request.method.as_str(), &segments[..]) {
+            ("GET", ["settings"]) if logged_in => {
+                Response::html(200, "<article><p>Settings</p></article>", true)
+            }
+            ("GET", ["settings"]) => Response::html(200, "<article></article>", false),
+            ("GET", [year]) => self.page(&format!("{year}/event.html"), logged_in),
+            ("GET", [year, "day", day]) => self.page(&day_file(year, day, "page.html"), logged_in),
+            ("GET", [year, "day", day, "input"]) if logged_in => {
+                match self.read(&day_file(year, day, "input.txt")) {
+                    Some(input) => Response::text(200, input),
+                    None => Response::not_found(true),
+                }
+            }
+            ("GET", [_, "day", _, "input"]) => Response::text(400, LOGGED_OUT_INPUT.into()),
+            ("POST", [year, "day", day, "answer"]) if logged_in => {
+                self.answer(year, day, &request.body)
+            }
+            ("GET", [year, "leaderboard", "private", "view", id]) if logged_in => {
+                match self.read(&format!("{year}/leaderboards/{id}")) {
+                    Some(json) => Response::json(json),
+                    None => Response::not_found(true),
+                }
+            }
+            _ => Response::not_found(logged_in),
+        }
+    }
+
+    fn page(&self, path: &str, logged_in: bool) -> Response {
+        match self.read(path) {
+            Some(main) => Response::html(200, &main, logged_in),
+            None => Response::not_found(logged_in),
+        }
+    }
+
+    fn answer(&self, year: &str, day: &str, form: &str) -> Response {
+        let field = |name: &str| {
+            form.split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| decode_form_value(value))
+        };
+        let expected = field("level")
+            .and_then(|level| level.parse::<usize>().ok())
+            .and_then(|level| {
+                self.read(&day_file(year, day, "answers.txt"))?
+                    .lines()
+                    .nth(level.checked_sub(1)?)
+                    .map(|line| line.trim().to_owned())
+            });
+        let answer = field("answer").unwrap_or_default();
+
+        let message = match expected {
+            None => "You don't seem to be solving the right level.  Did you already complete it?"
+                .to_owned(),
+            Some(expected) if expected == answer => {
+                "That's the right answer!  You are one gold star closer to saving Christmas."
+                    .to_owned()
+            }
+            Some(expected) => {
+                let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+                    (Ok(a), Ok(e)) if a > e => "; your answer is too high",
+                    (Ok(a), Ok(e)) if a < e => "; your answer is too low",
+                    _ => "",
+                };
+                format!("That's not the right answer{hint}.  Please wait one minute before trying again.")
+            }
+        };
+
+        Response::html(
+            200,
+            &format!("<article><p>{message} <a href=\"/{year}/day/{day}\">[Return to Day {day}]</a></p></article>"),
+            true,
+        )
+    }
+
+    fn read(&self, path: &str) -> Option<String> {
+        std::fs::read_to_string(self.dir.join(path)).ok()
+    }
+}
+
+/// HTTP server answering from fixtures on a local port, until dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+    stopped: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Start serving fixtures on a free local port.
+    pub fn start(fixtures: Fixtures) -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let requests = Arc::new(Mutex::new(vec![]));
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let thread = {
+            let requests = requests.clone();
+            let stopped = stopped.clone();
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stopped.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    let Ok(mut stream) = stream else { continue };
+                    if let Some(request) = read_request(&stream) {
+                        let response = fixtures.handle(&request);
+                        requests.lock().unwrap().push(request);
+                        let _ = write_response(&mut stream, &response);
+                    }
+                }
+            })
+        };
+
+        Ok(Self {
+            url,
+            requests,
+            stopped,
+            thread: Some(thread),
+        })
+    }
+
+    /// Base URL of the server.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Requests received so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+
+        // Wake the listener up
+        let _ = TcpStream::connect(self.url.trim_start_matches("http://"));
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn day_file(year: &str, day: &str, name: &str) -> String {
+    let day = day.parse::<u8>().unwrap_or_default();
+    format!("{year}/day{day:02}/{name}")
+}
+
+/// Page with the site header, showing the account when logged in.
+fn layout(main: &str, logged_in: bool) -> String {
+    let user = if logged_in {
+        format!("<div class=\"user\">{ACCOUNT_NAME} <span class=\"star-count\">2*</span></div>")
+    } else {
+        "<nav><ul><li><a href=\"/auth/login\">[Log In]</a></li></ul></nav>".to_owned()
+    };
+
+    format!("<!DOCTYPE html>\n<html lang=\"en-us\">\n<head><title>Advent of Code</title></head>\n<body>\n<header><div><h1 class=\"title-global\"><a href=\"/\">Advent of Code</a></h1>{user}</div></header>\n<main>\n{main}</main>\n</body>\n</html>\n")
+}
+
+fn decode_form_value(value: &str) -> String {
+    let mut output = vec![];
+    let mut bytes = value.bytes();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'+' => output.push(b' '),
+            b'%' => {
+                let digits = [bytes.next(), bytes.next()]
+                    .map(|b| b.and_then(|b| char::from(b).to_digit(16)));
+                if let [Some(high), Some(low)] = digits {
+                    output.push((high * 16 + low) as u8);
+                }
+            }
+            byte => output.push(byte),
+        }
+    }
+
+    String::from_utf8_lossy(&output).into_owned()
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line).ok()?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_owned();
+    let path = parts.next()?.to_owned();
+
+    let mut content_length = 0;
+    let mut session = None;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header).ok()?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        let (name, value) = header.split_once(':')?;
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => content_length = value.trim().parse().ok()?,
+            "cookie" => {
+                session = value
+                    .split(';')
+                    .filter_map(|c| c.trim().split_once('='))
+                    .find(|(name, _)| *name == "session")
+                    .map(|(_, value)| value.to_owned())
+            }
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(Request {
+        method,
+        path,
+        session,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> std::io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        if response.status == 200 { "OK" } else { "Error" },
+        response.content_type,
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fixtures_dir, Fixtures, Request};
+
+    fn request(method: &str, path: &str, session: Option<&str>, body: &str) -> Request {
+        Request {
+            method: method.into(),
+            path: path.into(),
+            session: session.map(Into::into),
+            body: body.into(),
+        }
+    }
+
+    #[test]
+    fn routes() {
+        let fixtures = Fixtures::new(fixtures_dir(), "token");
+
+        let input = fixtures.handle(&request("GET", "/2015/day/1/input", Some("token"), ""));
+        assert_eq!((input.status, input.body.as_str()), (200, "3 1 4 1 5\n"));
+        let logged_out = fixtures.handle(&request("GET", "/2015/day/1/input", Some("bad"), ""));
+        assert_eq!(logged_out.status, 400);
+
+        let page = fixtures.handle(&request("GET", "/2015/day/1", Some("token"), ""));
+        assert!(page.body.contains("--- Day 1: Mock Sums ---"));
+        assert!(page.body.contains("Mock User"));
+
+        let right = fixtures.handle(&request(
+            "POST",
+            "/2015/day/1/answer",
+            Some("token"),
+            "level=1&answer=14",
+        ));
+        assert!(right.body.contains("That's the right answer"));
+        let high = fixtures.handle(&request(
+            "POST",
+            "/2015/day/1/answer",
+            Some("token"),
+            "level=2&answer=100",
+        ));
+        assert!(high.body.contains("your answer is too high"));
+
+        let missing = fixtures.handle(&request("GET", "/2015/day/2", Some("token"), ""));
+        assert_eq!(missing.status, 404);
+    }
+}
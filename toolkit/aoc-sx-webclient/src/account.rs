@@ -0,0 +1,75 @@
+//! Logged-in account, and detection of logged-out responses.
+
+use scraper::{Html, Selector};
+
+/// Text of an input requested without a valid session.
+const INPUT_LOGIN_MARKER: &str = "Please log in";
+
+/// Link shown in the header of every page when logged out.
+const PAGE_LOGIN_MARKER: &str = "/auth/login";
+
+/// Account of the session token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Account {
+    /// Display name, e.g. `(anonymous user #123456)`.
+    pub name: String,
+    /// Stars of the current event, as shown in the header.
+    pub stars: Option<u32>,
+}
+
+impl Account {
+    /// Parse the account from a page header, `None` if logged out.
+    pub fn from_html(html: &str) -> Option<Self> {
+        let document = Html::parse_document(html);
+        let user_selector = Selector::parse("header div.user").unwrap();
+        let star_selector = Selector::parse("span.star-count").unwrap();
+
+        let user = document.select(&user_selector).next()?;
+        let name = user
+            .children()
+            .filter_map(|node| node.value().as_text())
+            .map(|text| text.trim())
+            .collect::<String>();
+        let stars = user.select(&star_selector).next().and_then(|s| {
+            s.text()
+                .collect::<String>()
+                .trim_end_matches('*')
+                .parse()
+                .ok()
+        });
+
+        (!name.is_empty()).then_some(Self { name, stars })
+    }
+}
+
+/// Whether a response was served to a logged-out user.
+pub fn is_logged_out(body: &str) -> bool {
+    body.contains(INPUT_LOGIN_MARKER) || body.contains(PAGE_LOGIN_MARKER)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_logged_out, Account};
+
+    #[test]
+    fn parse_account() {
+        let logged_in = r#"<html><body><header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1>
+            <nav><ul><li><a href="/2024/auth/logout">[Log Out]</a></li></ul></nav>
+            <div class="user">Jane Doe <span class="star-count">34*</span></div></div></header></body></html>"#;
+        assert_eq!(
+            Account::from_html(logged_in),
+            Some(Account {
+                name: "Jane Doe".into(),
+                stars: Some(34)
+            })
+        );
+        assert!(!is_logged_out(logged_in));
+
+        let logged_out = r#"<html><body><header><nav><ul><li><a href="/2024/auth/login">[Log In]</a></li></ul></nav></header></body></html>"#;
+        assert_eq!(Account::from_html(logged_out), None);
+        assert!(is_logged_out(logged_out));
+        assert!(is_logged_out(
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+        ));
+    }
+}
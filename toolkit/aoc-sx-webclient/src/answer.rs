@@ -0,0 +1,192 @@
+//! Answer responses.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "details", rename_all = "snake_case")]
+pub enum PuzzleAnswer {
+    /// Right answer, a star was gained.
+    Success(String),
+    /// Wrong answer, too high.
+    TooHigh(String),
+    /// Wrong answer, too low.
+    TooLow(String),
+    /// Wrong answer, without hint.
+    Failed(String),
+    /// Level already completed, or not unlocked yet.
+    WrongLevel(String),
+    /// Answer sent too recently, with the remaining wait.
+    TooManyRequests { message: String, wait: Duration },
+    /// Unrecognized response.
+    Unknown(String),
+}
+
+impl PuzzleAnswer {
+    /// Classify the text of an answer response.
+    pub fn from_response_text(text: &str) -> Self {
+        let message = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if message.contains("You gave an answer too recently") {
+            let wait = parse_remaining_wait(&message).unwrap_or_default();
+            Self::TooManyRequests { message, wait }
+        } else if message.contains("That's the right answer") {
+            Self::Success(message)
+        } else if message.contains("That's not the right answer") {
+            if message.contains("your answer is too high") {
+                Self::TooHigh(message)
+            } else if message.contains("your answer is too low") {
+                Self::TooLow(message)
+            } else {
+                Self::Failed(message)
+            }
+        } else if message.contains("You don't seem to be solving the right level") {
+            Self::WrongLevel(message)
+        } else {
+            Self::Unknown(message)
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Success(message)
+            | Self::TooHigh(message)
+            | Self::TooLow(message)
+            | Self::Failed(message)
+            | Self::WrongLevel(message)
+            | Self::Unknown(message)
+            | Self::TooManyRequests { message, .. } => message,
+        }
+    }
+
+    /// Whether the answer was rejected as wrong.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Self::TooHigh(_) | Self::TooLow(_) | Self::Failed(_))
+    }
+
+    /// Time to wait before sending another answer, if the response mentions it.
+    pub fn cooldown(&self) -> Option<Duration> {
+        match self {
+            Self::TooManyRequests { wait, .. } => Some(*wait),
+            other if other.is_wrong() => parse_wrong_answer_wait(other.message()),
+            _ => None,
+        }
+    }
+}
+
+/// Parse "You have 1m 13s left to wait".
+fn parse_remaining_wait(message: &str) -> Option<Duration> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+
+    message[start..end]
+        .split_whitespace()
+        .map(|token| {
+            let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Parse "please wait one minute before trying again" or "please wait 5 minutes ...".
+fn parse_wrong_answer_wait(message: &str) -> Option<Duration> {
+    let lowercase = message.to_lowercase();
+    let start = lowercase.find("wait ")? + "wait ".len();
+    let mut words = lowercase[start..].split_whitespace();
+    let amount = words.next()?;
+    let unit = words.next()?;
+
+    let amount = match amount {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "ten" => 10,
+        other => other.parse().ok()?,
+    };
+
+    match unit.trim_end_matches(['.', ',']) {
+        "second" | "seconds" => Some(Duration::from_secs(amount)),
+        "minute" | "minutes" => Some(Duration::from_secs(amount * 60)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::PuzzleAnswer;
+
+    #[test]
+    fn success() {
+        let answer = PuzzleAnswer::from_response_text(
+            "That's the right answer!  You are one gold star closer to saving your vacation. [Continue to Part Two]",
+        );
+        assert!(matches!(answer, PuzzleAnswer::Success(_)));
+        assert_eq!(answer.cooldown(), None);
+    }
+
+    #[test]
+    fn too_high_and_too_low() {
+        let answer = PuzzleAnswer::from_response_text(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the about page, or you can ask for hints on the subreddit.  Please wait one minute before trying again. [Return to Day 1]",
+        );
+        assert!(matches!(answer, PuzzleAnswer::TooHigh(_)));
+        assert_eq!(answer.cooldown(), Some(Duration::from_secs(60)));
+
+        let answer = PuzzleAnswer::from_response_text(
+            "That's not the right answer; your answer is too low.  Please wait 5 minutes before trying again.",
+        );
+        assert!(matches!(answer, PuzzleAnswer::TooLow(_)));
+        assert_eq!(answer.cooldown(), Some(Duration::from_secs(300)));
+    }
+
+    #[test]
+    fn failed_without_hint() {
+        let answer = PuzzleAnswer::from_response_text(
+            "That's not the right answer.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.",
+        );
+        assert!(matches!(answer, PuzzleAnswer::Failed(_)));
+    }
+
+    #[test]
+    fn wrong_level() {
+        let answer = PuzzleAnswer::from_response_text(
+            "You don't seem to be solving the right level.  Did you already complete it? [Return to Day 1]",
+        );
+        assert!(matches!(answer, PuzzleAnswer::WrongLevel(_)));
+    }
+
+    #[test]
+    fn too_many_requests() {
+        let answer = PuzzleAnswer::from_response_text(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 13s left to wait. [Return to Day 1]",
+        );
+        assert_eq!(answer.cooldown(), Some(Duration::from_secs(73)));
+
+        let answer = PuzzleAnswer::from_response_text(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 41s left to wait.",
+        );
+        assert!(matches!(
+            answer,
+            PuzzleAnswer::TooManyRequests { wait, .. } if wait == Duration::from_secs(41)
+        ));
+    }
+
+    #[test]
+    fn unknown() {
+        let answer = PuzzleAnswer::from_response_text("Something else entirely.");
+        assert!(matches!(answer, PuzzleAnswer::Unknown(_)));
+    }
+}
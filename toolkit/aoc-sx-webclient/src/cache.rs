@@ -0,0 +1,202 @@
+//! On-disk cache for puzzle pages, inputs and leaderboards.
+
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use aoc_sx_core::{
+    exercise::{ExerciseDay, ExerciseYear},
+    input::workspace_root,
+};
+
+const CACHE_DIR_ENV: &str = "AOC_CACHE_DIR";
+const CACHE_DIR_NAME: &str = ".cache";
+
+/// Time to live of a day page without its second part.
+const INCOMPLETE_PAGE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Time to live of an event page, whose stars change with each answer.
+const EVENT_PAGE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Minimum interval between two fetches of a private leaderboard, as requested by the API.
+const LEADERBOARD_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Marker of the second part in a day page.
+const PART_TWO_MARKER: &str = "id=\"part2\"";
+
+/// Cached resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    /// Day page.
+    Page(ExerciseDay),
+    /// Puzzle input.
+    Input(ExerciseDay),
+    /// Event page, with the calendar.
+    Event,
+    /// Private leaderboard JSON, by leaderboard ID.
+    Leaderboard(u64),
+}
+
+impl Resource {
+    fn path(&self, year: ExerciseYear) -> PathBuf {
+        let year = PathBuf::from(year.to_string());
+        match self {
+            Self::Page(day) => year
+                .join(format!("day{:02}", day.as_u8()))
+                .join("page.html"),
+            Self::Input(day) => year
+                .join(format!("day{:02}", day.as_u8()))
+                .join("input.txt"),
+            Self::Event => year.join("event.html"),
+            Self::Leaderboard(id) => year.join("leaderboards").join(format!("{id}.json")),
+        }
+    }
+
+    /// Time to live of a cached entry, `None` if it never expires.
+    fn ttl(&self, content: &str) -> Option<Duration> {
+        match self {
+            // Inputs never change
+            Self::Input(_) => None,
+            // Pages only change until the second part is unlocked
+            Self::Page(_) if content.contains(PART_TWO_MARKER) => None,
+            Self::Page(_) => Some(INCOMPLETE_PAGE_TTL),
+            Self::Event => Some(EVENT_PAGE_TTL),
+            Self::Leaderboard(_) => Some(LEADERBOARD_TTL),
+        }
+    }
+}
+
+impl std::fmt::Display for Resource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Page(day) => f.write_fmt(format_args!("page of day {day}")),
+            Self::Input(day) => f.write_fmt(format_args!("input of day {day}")),
+            Self::Event => f.write_str("event page"),
+            Self::Leaderboard(id) => f.write_fmt(format_args!("private leaderboard {id}")),
+        }
+    }
+}
+
+/// On-disk cache, organized by year, day and resource.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+    offline: bool,
+}
+
+impl Cache {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+            offline: false,
+        }
+    }
+
+    /// Cache directory from the `AOC_CACHE_DIR` variable, or `.cache` at the workspace root.
+    pub fn from_env() -> Self {
+        Self::new(
+            std::env::var_os(CACHE_DIR_ENV)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| workspace_root().join(CACHE_DIR_NAME)),
+        )
+    }
+
+    /// Only serve cached entries, never reaching the network.
+    pub fn with_offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Get a cached entry, if it exists and is still fresh.
+    pub fn get(&self, year: ExerciseYear, resource: Resource) -> Option<String> {
+        let path = self.dir.join(resource.path(year));
+        let content = std::fs::read_to_string(&path).ok()?;
+
+        if let Some(ttl) = resource.ttl(&content) {
+            let age = std::fs::metadata(&path)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+                .unwrap_or(Duration::MAX);
+
+            // Stale entries are still better than nothing when offline
+            if age > ttl && !self.offline {
+                return None;
+            }
+        }
+
+        Some(content)
+    }
+
+    /// Store an entry.
+    pub fn put(
+        &self,
+        year: ExerciseYear,
+        resource: Resource,
+        content: &str,
+    ) -> std::io::Result<()> {
+        let path = self.dir.join(resource.path(year));
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(path, content)
+    }
+
+    /// Remove an entry.
+    pub fn invalidate(&self, year: ExerciseYear, resource: Resource) -> std::io::Result<()> {
+        let path = self.dir.join(resource.path(year));
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_sx_core::exercise::{ExerciseDay, ExerciseYear};
+
+    use super::{Cache, Resource, INCOMPLETE_PAGE_TTL};
+
+    #[test]
+    fn ttl_rules() {
+        let day = ExerciseDay::try_from(1).unwrap();
+
+        assert_eq!(Resource::Input(day).ttl("1721\n979"), None);
+        assert_eq!(
+            Resource::Page(day).ttl("<h2>--- Day 1 ---</h2>"),
+            Some(INCOMPLETE_PAGE_TTL)
+        );
+        assert_eq!(
+            Resource::Page(day).ttl("<h2 id=\"part2\">--- Part Two ---</h2>"),
+            None
+        );
+    }
+
+    #[test]
+    fn put_get_invalidate() {
+        let dir = std::env::temp_dir().join(format!("aoc-sx-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir).with_offline(true);
+        let year = ExerciseYear::try_from(2020).unwrap();
+        let resource = Resource::Input(ExerciseDay::try_from(1).unwrap());
+
+        assert_eq!(cache.get(year, resource), None);
+        cache.put(year, resource, "1721").unwrap();
+        assert_eq!(cache.get(year, resource).as_deref(), Some("1721"));
+        cache.invalidate(year, resource).unwrap();
+        assert_eq!(cache.get(year, resource), None);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,75 @@
+//! Event pages, with the stars of each day.
+
+use std::collections::BTreeMap;
+
+use scraper::{Html, Selector};
+
+const DAY_CLASS_PREFIX: &str = "calendar-day";
+
+/// Stars of each day, from the calendar of an event page.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EventStars {
+    days: BTreeMap<u8, u8>,
+}
+
+impl EventStars {
+    /// Parse the calendar of an event page (`/{year}`).
+    pub fn from_html(html_content: &str) -> Self {
+        let document = Html::parse_document(html_content);
+        let selector = Selector::parse("a[class*='calendar-day']").unwrap();
+
+        let days = document
+            .root_element()
+            .select(&selector)
+            .filter_map(|link| {
+                let classes = link.value().classes().collect::<Vec<_>>();
+                let day = classes
+                    .iter()
+                    .find_map(|c| c.strip_prefix(DAY_CLASS_PREFIX)?.parse::<u8>().ok())?;
+                let stars = if classes.contains(&"calendar-verycomplete") {
+                    2
+                } else if classes.contains(&"calendar-complete") {
+                    1
+                } else {
+                    0
+                };
+
+                Some((day, stars))
+            })
+            .collect();
+
+        Self { days }
+    }
+
+    /// Stars of a day, from 0 to 2.
+    pub fn stars(&self, day: u8) -> u8 {
+        self.days.get(&day).copied().unwrap_or(0)
+    }
+
+    pub fn total(&self) -> u32 {
+        self.days.values().map(|s| u32::from(*s)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EventStars;
+
+    #[test]
+    fn parse_calendar() {
+        let stars = EventStars::from_html(
+            r#"<pre class="calendar">
+            <a aria-label="Day 1, two stars" href="/2023/day/1" class="calendar-day1 calendar-verycomplete">1</a>
+            <a aria-label="Day 2, one star" href="/2023/day/2" class="calendar-day2 calendar-complete">2</a>
+            <a aria-label="Day 3" href="/2023/day/3" class="calendar-day3">3</a>
+            <span class="calendar-day4">4</span>
+            </pre>"#,
+        );
+
+        assert_eq!(stars.stars(1), 2);
+        assert_eq!(stars.stars(2), 1);
+        assert_eq!(stars.stars(3), 0);
+        assert_eq!(stars.stars(4), 0);
+        assert_eq!(stars.total(), 3);
+    }
+}
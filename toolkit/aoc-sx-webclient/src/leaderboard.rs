@@ -0,0 +1,178 @@
+//! Private leaderboards.
+
+use std::{
+    collections::BTreeMap,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use aoc_sx_core::{
+    calendar::unlock_time,
+    exercise::{ExerciseDay, ExercisePart, ExerciseYear},
+};
+use serde::Deserialize;
+
+/// Private leaderboard, as returned by `/{year}/leaderboard/private/view/{id}.json`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PrivateLeaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+/// Leaderboard member.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    #[serde(default)]
+    pub global_score: u64,
+    /// Unix timestamp of the last star, 0 without stars.
+    pub last_star_ts: u64,
+    /// Completions, by day.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, DayCompletion>,
+}
+
+/// Stars of a member for a day.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DayCompletion {
+    #[serde(rename = "1")]
+    pub first: Star,
+    #[serde(rename = "2")]
+    pub second: Option<Star>,
+}
+
+/// A star, with its unix timestamp.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+    pub star_index: u64,
+}
+
+impl PrivateLeaderboard {
+    /// Members by rank: local score, then earliest last star.
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+
+    /// Days with at least one star.
+    pub fn days(&self) -> Vec<u8> {
+        let mut days = self
+            .members
+            .values()
+            .flat_map(|m| m.completion_day_level.keys().copied())
+            .collect::<Vec<_>>();
+        days.sort();
+        days.dedup();
+        days
+    }
+}
+
+impl Member {
+    /// Name, or the placeholder shown by the website for anonymous users.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Star of a part, if obtained.
+    pub fn star(&self, day: ExerciseDay, part: ExercisePart) -> Option<&Star> {
+        let completion = self.completion_day_level.get(&day.as_u8())?;
+        match part {
+            ExercisePart::First => Some(&completion.first),
+            ExercisePart::Second => completion.second.as_ref(),
+        }
+    }
+
+    /// Time from the unlock of a day to a star.
+    pub fn completion_time(
+        &self,
+        year: ExerciseYear,
+        day: ExerciseDay,
+        part: ExercisePart,
+    ) -> Option<Duration> {
+        let star = self.star(day, part)?;
+        let unlock = unlock_time(year, day).duration_since(UNIX_EPOCH).ok()?;
+        star.get_star_ts
+            .checked_sub(unlock.as_secs())
+            .map(Duration::from_secs)
+    }
+
+    /// Time between the first and second star of a day.
+    pub fn part_delta(&self, day: ExerciseDay) -> Option<Duration> {
+        let first = self.star(day, ExercisePart::First)?;
+        let second = self.star(day, ExercisePart::Second)?;
+        second
+            .get_star_ts
+            .checked_sub(first.get_star_ts)
+            .map(Duration::from_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use aoc_sx_core::exercise::{ExerciseDay, ExercisePart, ExerciseYear};
+
+    use super::PrivateLeaderboard;
+
+    const SAMPLE: &str = r#"{
+        "owner_id": 1,
+        "event": "2023",
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 5, "global_score": 0,
+                "last_star_ts": 1701493800,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1701407100, "star_index": 10},
+                        "2": {"get_star_ts": 1701407700, "star_index": 20}
+                    },
+                    "2": {"1": {"get_star_ts": 1701493800, "star_index": 30}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 4, "global_score": 0,
+                "last_star_ts": 1701408000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1701406900, "star_index": 5},
+                        "2": {"get_star_ts": 1701408000, "star_index": 25}
+                    }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn standings_and_times() {
+        let leaderboard: PrivateLeaderboard = serde_json::from_str(SAMPLE).unwrap();
+        let year = ExerciseYear::try_from(2023).unwrap();
+        let day = ExerciseDay::try_from(1).unwrap();
+
+        let standings = leaderboard.standings();
+        assert_eq!(standings[0].display_name(), "Alice");
+        assert_eq!(standings[1].display_name(), "(anonymous user #2)");
+        assert_eq!(leaderboard.days(), vec![1, 2]);
+
+        let alice = standings[0];
+        assert_eq!(
+            alice.completion_time(year, day, ExercisePart::First),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(alice.part_delta(day), Some(Duration::from_secs(600)));
+        assert_eq!(alice.part_delta(ExerciseDay::try_from(2).unwrap()), None);
+    }
+}
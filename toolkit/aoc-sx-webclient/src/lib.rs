@@ -1,72 +1,171 @@
-use std::time::Duration;
+pub mod account;
+pub mod answer;
+pub mod cache;
+pub mod event;
+pub mod leaderboard;
+pub mod submissions;
+pub mod throttle;
+pub mod transport;
 
-use aoc_sx_core::exercise::{ExerciseDay, ExercisePart, ExerciseYear};
-use cookie_store::CookieStore;
+pub use answer::PuzzleAnswer;
+
+use aoc_sx_core::exercise::{Answer, ExerciseDay, ExercisePart, ExerciseYear};
 use scraper::{Html, Selector};
-use ureq::{Agent, AgentBuilder, Cookie};
 use url::Url;
 
+use crate::{
+    account::{is_logged_out, Account},
+    cache::{Cache, Resource},
+    event::EventStars,
+    leaderboard::PrivateLeaderboard,
+    throttle::Throttle,
+    transport::{Method, Request, Transport, UreqTransport},
+};
+
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
     #[error("Network error: {0}")]
     NetworkError(String),
+
+    #[error("HTTP status {status} for {url}.")]
+    HttpStatus { url: String, status: u16 },
+
+    #[error("Rate limited by the server for {url}, try again later.")]
+    RateLimited { url: String },
+
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(#[from] url::ParseError),
+
+    #[error("Parse error: {0}")]
+    ParseError(String),
+
+    #[error("Not logged in: the session token is missing, invalid or expired.")]
+    Unauthenticated,
+
+    #[error("Offline mode: {0}")]
+    Offline(String),
+
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
 }
 
-const TIMEOUT_DURATION: Duration = Duration::from_secs(5);
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT_PREFIX: &str = concat!(
+    "aoc-sx/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/Srynetix/advent-of-code"
+);
 
 #[derive(Debug)]
 pub struct Client {
-    agent: Agent,
+    transport: Box<dyn Transport>,
+    base_url: Url,
+    cache: Option<Cache>,
+    throttle: Throttle,
+    user_agent: String,
 }
 
 impl Client {
     pub fn new(session_token: String) -> Self {
-        let cookie = Cookie::build(("session", session_token))
-            .domain(".adventofcode.com")
-            .build();
-        let mut store = CookieStore::default();
-        let url = Url::try_from("https://adventofcode.com").unwrap();
-        store.insert_raw(&cookie, &url).unwrap();
+        Self {
+            transport: Box::new(UreqTransport::new(session_token)),
+            base_url: Url::parse(DEFAULT_BASE_URL).expect("Default base URL should be valid."),
+            cache: None,
+            throttle: Throttle::default(),
+            user_agent: format!("{USER_AGENT_PREFIX})"),
+        }
+    }
 
-        let agent = AgentBuilder::new()
-            .timeout_read(TIMEOUT_DURATION)
-            .timeout_write(TIMEOUT_DURATION)
-            .cookie_store(store)
-            .build();
+    /// Use another server, e.g. a local mock or a proxy under a path prefix.
+    pub fn with_base_url(mut self, mut base_url: Url) -> Self {
+        // Keep the last path segment when joining
+        if !base_url.path().ends_with('/') {
+            base_url.set_path(&format!("{}/", base_url.path()));
+        }
+
+        self.base_url = base_url;
+        self
+    }
+
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Send requests through another transport, e.g. to record or replay them.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Box::new(transport);
+        self
+    }
 
-        Self { agent }
+    /// Throttle outgoing requests.
+    pub fn with_throttle(mut self, throttle: Throttle) -> Self {
+        self.throttle = throttle;
+        self
     }
 
-    fn exercise_page_to_url(&self, year: ExerciseYear, day: ExerciseDay) -> Url {
-        Url::try_from(&format!("https://adventofcode.com/{year}/day/{day}")[..]).unwrap()
+    /// Include a contact string (e-mail, Reddit user, ...) in the User-Agent header.
+    pub fn with_user_agent_contact(mut self, contact: &str) -> Self {
+        self.user_agent = format!("{USER_AGENT_PREFIX}; {contact})");
+        self
+    }
+
+    pub fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    /// Cache pages and inputs on disk.
+    pub fn with_cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn cache(&self) -> Option<&Cache> {
+        self.cache.as_ref()
+    }
+
+    /// URL of a server path, relative to the base URL and its path prefix.
+    fn url(&self, path: &str) -> Result<Url, Error> {
+        Ok(self.base_url.join(path.trim_start_matches('/'))?)
+    }
+
+    fn exercise_page_to_url(&self, year: ExerciseYear, day: ExerciseDay) -> Result<Url, Error> {
+        self.url(&format!("/{year}/day/{day}"))
     }
 
     pub fn send_answer(
         &self,
-        answer: &str,
+        answer: &Answer,
         year: ExerciseYear,
         day: ExerciseDay,
         part: ExercisePart,
     ) -> Result<PuzzleAnswer, Error> {
-        let response = self
-            .agent
-            .post(self.get_exercise_answer_url(year, day).as_str())
-            .send_form(&[("level", part.as_level()), ("answer", answer)])
-            .map_err(|e| Error::NetworkError(e.to_string()))?;
+        if self.cache.as_ref().is_some_and(|c| c.is_offline()) {
+            return Err(Error::Offline("answers cannot be sent.".into()));
+        }
+
+        let body = self.send(
+            Method::Post,
+            &self.get_exercise_answer_url(year, day)?,
+            &[("level", part.as_level()), ("answer", &answer.to_string())],
+        )?;
 
-        let body = response.into_string().unwrap();
         let document = Html::parse_document(&body);
         let selector = Selector::parse("article").unwrap();
-        let node = document.root_element().select(&selector).next().unwrap();
+        let node = document
+            .root_element()
+            .select(&selector)
+            .next()
+            .ok_or_else(|| Error::ParseError("answer response has no <article>.".into()))?;
         let node_text = node.text().collect::<String>();
+        let answer = PuzzleAnswer::from_response_text(&node_text);
 
-        if node_text.contains("You gave an answer too recently") {
-            Ok(PuzzleAnswer::TooManyRequests(node_text))
-        } else if node_text.contains("That's not the right answer") {
-            Ok(PuzzleAnswer::Failed(node_text))
-        } else {
-            Ok(PuzzleAnswer::Success(node_text))
+        // The day and event pages change once an answer is accepted
+        if let (PuzzleAnswer::Success(_), Some(cache)) = (&answer, &self.cache) {
+            cache.invalidate(year, Resource::Page(day))?;
+            cache.invalidate(year, Resource::Event)?;
         }
+
+        Ok(answer)
     }
 
     pub fn fetch_input_page(
@@ -74,48 +173,135 @@ impl Client {
         year: ExerciseYear,
         day: ExerciseDay,
     ) -> Result<PuzzleInput, Error> {
-        let input_url = self.get_exercise_input_url(year, day);
-
-        let input_response = self
-            .agent
-            .get(input_url.as_str())
-            .call()
-            .map_err(|e| Error::NetworkError(e.to_string()))?;
-        let input_body = input_response.into_string().unwrap();
+        let input_url = self.get_exercise_input_url(year, day)?;
+        let input_body = self.fetch_cached(year, Resource::Input(day), &input_url)?;
 
         Ok(PuzzleInput(input_body))
     }
 
+    /// Fetch the account of the session token, never cached.
+    pub fn fetch_account(&self) -> Result<Account, Error> {
+        if self.cache.as_ref().is_some_and(|c| c.is_offline()) {
+            return Err(Error::Offline(
+                "the session token cannot be checked.".into(),
+            ));
+        }
+
+        let url = self.url("/settings")?;
+        let body = self.send(Method::Get, &url, &[])?;
+
+        Account::from_html(&body).ok_or(Error::Unauthenticated)
+    }
+
+    /// Fetch the stars of each day of an event.
+    pub fn fetch_event_stars(&self, year: ExerciseYear) -> Result<EventStars, Error> {
+        let url = self.url(&format!("/{year}"))?;
+        let body = self.fetch_cached(year, Resource::Event, &url)?;
+
+        Ok(EventStars::from_html(&body))
+    }
+
+    /// Fetch a private leaderboard, at most once every 15 minutes when cached.
+    pub fn fetch_private_leaderboard(
+        &self,
+        year: ExerciseYear,
+        id: u64,
+    ) -> Result<PrivateLeaderboard, Error> {
+        let url = self.url(&format!("/{year}/leaderboard/private/view/{id}.json"))?;
+        let resource = Resource::Leaderboard(id);
+        let body = self.fetch_cached(year, resource, &url)?;
+
+        serde_json::from_str(&body).map_err(|e| {
+            // Do not keep an unexpected response (e.g. a login page) in cache
+            if let Some(cache) = &self.cache {
+                let _ = cache.invalidate(year, resource);
+            }
+            Error::ParseError(format!("{resource} of {year}: {e}"))
+        })
+    }
+
     pub fn fetch_exercise_page(
         &self,
         year: ExerciseYear,
         day: ExerciseDay,
     ) -> Result<ExercisePage, Error> {
-        let page_url = self.exercise_page_to_url(year, day);
-        let input_url = self.get_exercise_input_url(year, day);
-
-        let page_response = self
-            .agent
-            .get(page_url.as_str())
-            .call()
-            .map_err(|e| Error::NetworkError(e.to_string()))?;
-        let page_body = page_response.into_string().unwrap();
+        let page_url = self.exercise_page_to_url(year, day)?;
+        let input_url = self.get_exercise_input_url(year, day)?;
+        let page_body = self.fetch_cached(year, Resource::Page(day), &page_url)?;
 
-        let puzzle_input = self.fetch_input_page(year, day)?;
         Ok(ExercisePage {
             input_url,
             page_url,
             page_content: HtmlContent(page_body),
-            puzzle_input,
         })
     }
 
-    fn get_exercise_input_url(&self, year: ExerciseYear, day: ExerciseDay) -> Url {
-        Url::try_from(&format!("{}/input", self.exercise_page_to_url(year, day))[..]).unwrap()
+    fn fetch_cached(
+        &self,
+        year: ExerciseYear,
+        resource: Resource,
+        url: &Url,
+    ) -> Result<String, Error> {
+        if let Some(cache) = &self.cache {
+            // Older versions could cache logged-out responses
+            if let Some(content) = cache.get(year, resource).filter(|c| !is_logged_out(c)) {
+                return Ok(content);
+            }
+
+            if cache.is_offline() {
+                return Err(Error::Offline(format!(
+                    "{resource} of {year} is not cached."
+                )));
+            }
+        }
+
+        let body = self.send(Method::Get, url, &[])?;
+        if let Some(cache) = &self.cache {
+            cache.put(year, resource, &body)?;
+        }
+
+        Ok(body)
     }
 
-    fn get_exercise_answer_url(&self, year: ExerciseYear, day: ExerciseDay) -> Url {
-        Url::try_from(&format!("{}/answer", self.exercise_page_to_url(year, day))[..]).unwrap()
+    /// Send a request, rejecting errors and responses served to logged-out users.
+    fn send(&self, method: Method, url: &Url, form: &[(&str, &str)]) -> Result<String, Error> {
+        self.throttle.wait()?;
+        let response = self.transport.send(&Request {
+            method,
+            url,
+            user_agent: &self.user_agent,
+            form,
+        })?;
+
+        // Inputs requested without a valid session are a 400 with a login message
+        if is_logged_out(&response.body) {
+            return Err(Error::Unauthenticated);
+        }
+
+        match response.status {
+            200 => {}
+            429 => {
+                return Err(Error::RateLimited {
+                    url: url.to_string(),
+                })
+            }
+            status => {
+                return Err(Error::HttpStatus {
+                    url: url.to_string(),
+                    status,
+                })
+            }
+        }
+
+        Ok(response.body)
+    }
+
+    fn get_exercise_input_url(&self, year: ExerciseYear, day: ExerciseDay) -> Result<Url, Error> {
+        self.url(&format!("/{year}/day/{day}/input"))
+    }
+
+    fn get_exercise_answer_url(&self, year: ExerciseYear, day: ExerciseDay) -> Result<Url, Error> {
+        self.url(&format!("/{year}/day/{day}/answer"))
     }
 }
 
@@ -150,12 +336,32 @@ pub struct ExercisePage {
     pub page_url: Url,
     pub input_url: Url,
     pub page_content: HtmlContent,
-    pub puzzle_input: PuzzleInput,
 }
 
-#[derive(Debug)]
-pub enum PuzzleAnswer {
-    Success(String),
-    Failed(String),
-    TooManyRequests(String),
+#[cfg(test)]
+mod tests {
+    use aoc_sx_core::exercise::{ExerciseDay, ExerciseYear};
+    use url::Url;
+
+    use super::Client;
+
+    #[test]
+    fn urls_keep_base_path() {
+        let year = ExerciseYear::try_from(2020).unwrap();
+        let day = ExerciseDay::try_from(1).unwrap();
+
+        let client = Client::new("token".into());
+        assert_eq!(
+            client.get_exercise_input_url(year, day).unwrap().as_str(),
+            "https://adventofcode.com/2020/day/1/input"
+        );
+
+        for base in ["http://proxy/aoc", "http://proxy/aoc/"] {
+            let client = Client::new("token".into()).with_base_url(Url::parse(base).unwrap());
+            assert_eq!(
+                client.exercise_page_to_url(year, day).unwrap().as_str(),
+                "http://proxy/aoc/2020/day/1"
+            );
+        }
+    }
 }
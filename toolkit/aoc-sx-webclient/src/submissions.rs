@@ -0,0 +1,295 @@
+//! Local history of submitted answers, used to avoid pointless submissions.
+
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_sx_core::{
+    exercise::{Answer, ExerciseDay, ExercisePart, ExerciseYear},
+    input::workspace_root,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::PuzzleAnswer;
+
+const SUBMISSIONS_FILE_ENV: &str = "AOC_SUBMISSIONS_FILE";
+const SUBMISSIONS_FILE_NAME: &str = "submissions.json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Could not access submissions file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Could not read submissions file: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// Reason to refuse a submission before sending it.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Refusal {
+    #[error("This part is already solved (answer: {0}).")]
+    AlreadySolved(String),
+
+    #[error("Answer {0} was already rejected.")]
+    AlreadyRejected(String),
+
+    #[error("Answer {answer} is too high, {bound} was already too high.")]
+    TooHigh { answer: String, bound: i128 },
+
+    #[error("Answer {answer} is too low, {bound} was already too low.")]
+    TooLow { answer: String, bound: i128 },
+
+    #[error("Still waiting, {}s left before trying again.", .0.as_secs())]
+    Cooldown(Duration),
+}
+
+/// A submitted answer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Unix timestamp, in seconds.
+    pub timestamp: u64,
+    pub answer: String,
+    pub response: PuzzleAnswer,
+}
+
+/// Submission history, backed by a JSON file.
+#[derive(Debug)]
+pub struct SubmissionHistory {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionHistory {
+    /// Load the history from the `AOC_SUBMISSIONS_FILE` variable, or `submissions.json` at the workspace root.
+    pub fn from_env() -> Result<Self, Error> {
+        Self::load(
+            std::env::var_os(SUBMISSIONS_FILE_ENV)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| workspace_root().join(SUBMISSIONS_FILE_NAME)),
+        )
+    }
+
+    /// Load the history from a file, which may not exist yet.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let submissions = if path.exists() {
+            serde_json::from_str(&std::fs::read_to_string(&path)?)?
+        } else {
+            vec![]
+        };
+
+        Ok(Self { path, submissions })
+    }
+
+    /// Save the history to its file.
+    pub fn save(&self) -> Result<(), Error> {
+        std::fs::write(&self.path, serde_json::to_string_pretty(&self.submissions)?)?;
+        Ok(())
+    }
+
+    /// Submissions for a part, oldest first.
+    pub fn submissions(
+        &self,
+        year: ExerciseYear,
+        day: ExerciseDay,
+        part: ExercisePart,
+    ) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter().filter(move |s| {
+            s.year == year.as_u16() && s.day == day.as_u8() && s.part == part.as_u8()
+        })
+    }
+
+    /// Record a submission.
+    pub fn record(
+        &mut self,
+        year: ExerciseYear,
+        day: ExerciseDay,
+        part: ExercisePart,
+        answer: &Answer,
+        response: PuzzleAnswer,
+        now: SystemTime,
+    ) {
+        self.submissions.push(Submission {
+            year: year.as_u16(),
+            day: day.as_u8(),
+            part: part.as_u8(),
+            timestamp: unix_timestamp(now),
+            answer: answer.to_string(),
+            response,
+        });
+    }
+
+    /// Check whether an answer is worth sending, given previous submissions.
+    pub fn check(
+        &self,
+        year: ExerciseYear,
+        day: ExerciseDay,
+        part: ExercisePart,
+        answer: &Answer,
+        now: SystemTime,
+    ) -> Result<(), Refusal> {
+        let answer_str = answer.to_string();
+        let now = unix_timestamp(now);
+
+        for submission in self.submissions(year, day, part) {
+            let response = &submission.response;
+
+            if let PuzzleAnswer::Success(_) = response {
+                return Err(Refusal::AlreadySolved(submission.answer.clone()));
+            }
+
+            if response.is_wrong() && *answer == submission.answer {
+                return Err(Refusal::AlreadyRejected(answer_str));
+            }
+
+            if let (Answer::Integer(value), Ok(Answer::Integer(bound))) =
+                (answer, submission.answer.parse::<Answer>())
+            {
+                match response {
+                    PuzzleAnswer::TooHigh(_) if *value >= bound => {
+                        return Err(Refusal::TooHigh {
+                            answer: answer_str,
+                            bound,
+                        });
+                    }
+                    PuzzleAnswer::TooLow(_) if *value <= bound => {
+                        return Err(Refusal::TooLow {
+                            answer: answer_str,
+                            bound,
+                        });
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        // The wait after a wrong answer applies to the whole account, whatever the part
+        let cooldown_end = self
+            .submissions
+            .iter()
+            .filter_map(|s| Some((s.timestamp, s.response.cooldown()?)))
+            .max_by_key(|(timestamp, _)| *timestamp)
+            .map(|(timestamp, cooldown)| timestamp + cooldown.as_secs());
+        match cooldown_end {
+            Some(end) if end > now => Err(Refusal::Cooldown(Duration::from_secs(end - now))),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn unix_timestamp(instant: SystemTime) -> u64 {
+    instant
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use aoc_sx_core::exercise::{Answer, ExerciseDay, ExercisePart, ExerciseYear};
+
+    use crate::PuzzleAnswer;
+
+    use super::{Refusal, SubmissionHistory};
+
+    const WAIT_ONE_MINUTE: &str = "Please wait one minute before trying again.";
+
+    fn history() -> SubmissionHistory {
+        SubmissionHistory {
+            path: "submissions.json".into(),
+            submissions: vec![],
+        }
+    }
+
+    #[test]
+    fn guards_against_known_wrong_answers() {
+        let year = ExerciseYear::try_from(2020).unwrap();
+        let day = ExerciseDay::try_from(1).unwrap();
+        let part = ExercisePart::First;
+        let then = SystemTime::now() - Duration::from_secs(3600);
+        let now = SystemTime::now();
+
+        let mut history = history();
+        history.record(
+            year,
+            day,
+            part,
+            &Answer::from(100_u32),
+            PuzzleAnswer::TooHigh(WAIT_ONE_MINUTE.into()),
+            then,
+        );
+        history.record(
+            year,
+            day,
+            part,
+            &Answer::from(10_u32),
+            PuzzleAnswer::TooLow(WAIT_ONE_MINUTE.into()),
+            then,
+        );
+        history.record(
+            year,
+            day,
+            part,
+            &Answer::from(50_u32),
+            PuzzleAnswer::Failed(WAIT_ONE_MINUTE.into()),
+            then,
+        );
+
+        let check = |answer: u32| history.check(year, day, part, &Answer::from(answer), now);
+        assert!(matches!(check(100), Err(Refusal::AlreadyRejected(_))));
+        assert!(matches!(
+            check(150),
+            Err(Refusal::TooHigh { bound: 100, .. })
+        ));
+        assert!(matches!(check(5), Err(Refusal::TooLow { bound: 10, .. })));
+        assert!(matches!(check(50), Err(Refusal::AlreadyRejected(_))));
+        assert_eq!(check(42), Ok(()));
+    }
+
+    #[test]
+    fn guards_against_cooldown_and_solved_parts() {
+        let year = ExerciseYear::try_from(2020).unwrap();
+        let day = ExerciseDay::try_from(1).unwrap();
+        let now = SystemTime::now();
+
+        let mut history = history();
+        history.record(
+            year,
+            day,
+            ExercisePart::First,
+            &Answer::from(1_u32),
+            PuzzleAnswer::Failed(WAIT_ONE_MINUTE.into()),
+            now - Duration::from_secs(20),
+        );
+        assert!(matches!(
+            history.check(year, day, ExercisePart::First, &Answer::from(2_u32), now),
+            Err(Refusal::Cooldown(wait)) if wait == Duration::from_secs(40)
+        ));
+
+        // Whatever the part or day
+        let other_day = ExerciseDay::try_from(2).unwrap();
+        assert!(matches!(
+            history.check(year, other_day, ExercisePart::Second, &Answer::from(2_u32), now),
+            Err(Refusal::Cooldown(wait)) if wait == Duration::from_secs(40)
+        ));
+
+        history.record(
+            year,
+            day,
+            ExercisePart::Second,
+            &Answer::from(3_u32),
+            PuzzleAnswer::Success("That's the right answer!".into()),
+            now,
+        );
+        assert!(matches!(
+            history.check(year, day, ExercisePart::Second, &Answer::from(4_u32), now),
+            Err(Refusal::AlreadySolved(_))
+        ));
+    }
+}
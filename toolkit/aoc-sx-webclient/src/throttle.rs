@@ -0,0 +1,123 @@
+//! Client-side request throttling.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Default minimum interval between two requests.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Keeps a minimum interval between requests, optionally across process runs through a state file.
+#[derive(Debug)]
+pub struct Throttle {
+    min_interval: Duration,
+    state_file: Option<PathBuf>,
+    last_request: Mutex<Option<SystemTime>>,
+}
+
+impl Default for Throttle {
+    fn default() -> Self {
+        Self::new(DEFAULT_MIN_INTERVAL)
+    }
+}
+
+impl Throttle {
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            state_file: None,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Persist the last request instant in a state file.
+    pub fn with_state_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.state_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    /// Time to wait before the next request is allowed.
+    pub fn remaining(&self) -> Duration {
+        self.last_request()
+            .and_then(|last| SystemTime::now().duration_since(last).ok())
+            .map(|elapsed| self.min_interval.saturating_sub(elapsed))
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Wait until the next request is allowed, then record it.
+    pub fn wait(&self) -> std::io::Result<()> {
+        let remaining = self.remaining();
+        if !remaining.is_zero() {
+            tracing::debug!("Throttling request for {remaining:?}");
+            std::thread::sleep(remaining);
+        }
+
+        self.record(SystemTime::now())
+    }
+
+    fn last_request(&self) -> Option<SystemTime> {
+        let in_memory = *self.last_request.lock().unwrap();
+        let on_disk = self
+            .state_file
+            .as_ref()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| content.trim().parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+
+        in_memory.max(on_disk)
+    }
+
+    fn record(&self, instant: SystemTime) -> std::io::Result<()> {
+        *self.last_request.lock().unwrap() = Some(instant);
+
+        if let Some(path) = &self.state_file {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            let millis = instant
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0);
+            std::fs::write(path, millis.to_string())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::Throttle;
+
+    #[test]
+    fn remaining_after_request() {
+        let throttle = Throttle::new(Duration::from_secs(60));
+        assert_eq!(throttle.remaining(), Duration::ZERO);
+
+        throttle.record(SystemTime::now()).unwrap();
+        assert!(throttle.remaining() > Duration::from_secs(59));
+    }
+
+    #[test]
+    fn state_is_kept_across_instances() {
+        let path = std::env::temp_dir().join(format!("aoc-sx-throttle-{}", std::process::id()));
+
+        Throttle::new(Duration::from_secs(60))
+            .with_state_file(&path)
+            .record(SystemTime::now())
+            .unwrap();
+        let throttle = Throttle::new(Duration::from_secs(60)).with_state_file(&path);
+        assert!(throttle.remaining() > Duration::from_secs(59));
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,240 @@
+//! HTTP transports: live requests, recording them to fixture files, or replaying these fixtures.
+
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use ureq::{Agent, AgentBuilder};
+use url::Url;
+
+use crate::Error;
+
+const TIMEOUT_DURATION: Duration = Duration::from_secs(5);
+
+/// Replacement of secrets in recorded fixtures.
+const SCRUBBED: &str = "[scrubbed]";
+
+/// HTTP method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    Get,
+    Post,
+}
+
+impl Method {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+        }
+    }
+}
+
+/// Outgoing request.
+#[derive(Debug, Clone)]
+pub struct Request<'a> {
+    pub method: Method,
+    pub url: &'a Url,
+    pub user_agent: &'a str,
+    /// Form fields, sent URL-encoded with a POST.
+    pub form: &'a [(&'a str, &'a str)],
+}
+
+/// Response, whatever its status code.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Sends requests.
+pub trait Transport: std::fmt::Debug + Send + Sync {
+    fn send(&self, request: &Request) -> Result<Response, Error>;
+}
+
+/// Live transport, sending the session cookie.
+#[derive(Debug)]
+pub struct UreqTransport {
+    agent: Agent,
+    session_token: String,
+}
+
+impl UreqTransport {
+    pub fn new(session_token: String) -> Self {
+        let agent = AgentBuilder::new()
+            .timeout_read(TIMEOUT_DURATION)
+            .timeout_write(TIMEOUT_DURATION)
+            .build();
+
+        Self {
+            agent,
+            session_token,
+        }
+    }
+}
+
+impl Transport for UreqTransport {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        let http_request = self
+            .agent
+            .request(request.method.as_str(), request.url.as_str())
+            .set("User-Agent", request.user_agent)
+            .set("Cookie", &format!("session={}", self.session_token));
+
+        let result = match request.method {
+            Method::Get => http_request.call(),
+            Method::Post => http_request.send_form(request.form),
+        };
+
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(Error::NetworkError(e.to_string())),
+        };
+
+        Ok(Response {
+            status: response.status(),
+            body: response.into_string()?,
+        })
+    }
+}
+
+/// Records responses of another transport to fixture files, scrubbing secrets.
+#[derive(Debug)]
+pub struct Recorder<T: Transport> {
+    inner: T,
+    dir: PathBuf,
+    secrets: Vec<String>,
+}
+
+impl<T: Transport> Recorder<T> {
+    pub fn new<P: AsRef<Path>>(inner: T, dir: P) -> Self {
+        Self {
+            inner,
+            dir: dir.as_ref().to_path_buf(),
+            secrets: vec![],
+        }
+    }
+
+    /// Never write a secret, e.g. the session token, to fixtures.
+    pub fn with_secret(mut self, secret: &str) -> Self {
+        self.secrets.push(secret.to_owned());
+        self
+    }
+}
+
+impl<T: Transport> Transport for Recorder<T> {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        let mut response = self.inner.send(request)?;
+        for secret in self.secrets.iter().filter(|s| !s.is_empty()) {
+            response.body = response.body.replace(secret, SCRUBBED);
+        }
+
+        let path = self.dir.join(fixture_name(request));
+        std::fs::create_dir_all(&self.dir)?;
+        let content = serde_json::to_string_pretty(&response)
+            .map_err(|e| Error::ParseError(format!("{path:?}: {e}")))?;
+        std::fs::write(&path, content)?;
+        tracing::debug!("Recorded {path:?}");
+
+        Ok(response)
+    }
+}
+
+/// Serves responses recorded by a [`Recorder`], never reaching the network.
+#[derive(Debug)]
+pub struct Replay {
+    dir: PathBuf,
+}
+
+impl Replay {
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+}
+
+impl Transport for Replay {
+    fn send(&self, request: &Request) -> Result<Response, Error> {
+        let path = self.dir.join(fixture_name(request));
+        let content = std::fs::read_to_string(&path).map_err(|_| {
+            Error::Offline(format!(
+                "no recording of {} {} in {path:?}.",
+                request.method.as_str(),
+                request.url.path()
+            ))
+        })?;
+
+        serde_json::from_str(&content).map_err(|e| Error::ParseError(format!("{path:?}: {e}")))
+    }
+}
+
+/// Fixture file of a request, from its method, path and form, e.g. `POST-2020-day-1-answer-level-1-answer-42.json`.
+pub fn fixture_name(request: &Request) -> String {
+    let form = request.form.iter().flat_map(|(key, value)| [*key, *value]);
+    let name = std::iter::once(request.method.as_str())
+        .chain(request.url.path().split('/'))
+        .chain(form)
+        .flat_map(|part| part.split(|c: char| !c.is_ascii_alphanumeric()))
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+
+    format!("{name}.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use super::{fixture_name, Method, Recorder, Replay, Request, Response, Transport};
+    use crate::Error;
+
+    /// Transport echoing a secret in its responses.
+    #[derive(Debug)]
+    struct Echo;
+
+    impl Transport for Echo {
+        fn send(&self, request: &Request) -> Result<Response, Error> {
+            Ok(Response {
+                status: 200,
+                body: format!("{} for secret-token", request.url.path()),
+            })
+        }
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let dir = std::env::temp_dir().join(format!("aoc-sx-recordings-{}", std::process::id()));
+        let url = Url::parse("https://adventofcode.com/2020/day/1/answer").unwrap();
+        let request = Request {
+            method: Method::Post,
+            url: &url,
+            user_agent: "test",
+            form: &[("level", "1"), ("answer", "42")],
+        };
+        assert_eq!(
+            fixture_name(&request),
+            "POST-2020-day-1-answer-level-1-answer-42.json"
+        );
+
+        let recorder = Recorder::new(Echo, &dir).with_secret("secret-token");
+        let recorded = recorder.send(&request).unwrap();
+        assert_eq!(recorded.body, "/2020/day/1/answer for [scrubbed]");
+        assert_eq!(Replay::new(&dir).send(&request).unwrap(), recorded);
+
+        let other = Request {
+            form: &[("level", "2"), ("answer", "42")],
+            ..request
+        };
+        assert!(matches!(
+            Replay::new(&dir).send(&other),
+            Err(Error::Offline(_))
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
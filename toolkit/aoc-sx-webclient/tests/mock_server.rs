@@ -0,0 +1,111 @@
+use std::time::Duration;
+
+use aoc_sx_core::exercise::{Answer, ExerciseDay, ExercisePart, ExerciseYear};
+use aoc_sx_mock::{fixtures_dir, Fixtures, MockServer, ACCOUNT_NAME};
+use aoc_sx_webclient::{throttle::Throttle, Client, Error, PuzzleAnswer};
+use url::Url;
+
+const SESSION: &str = "mock-session";
+
+fn client(server: &MockServer, session: &str) -> Client {
+    Client::new(session.into())
+        .with_base_url(Url::parse(server.url()).unwrap())
+        .with_throttle(Throttle::new(Duration::ZERO))
+}
+
+fn start() -> MockServer {
+    MockServer::start(Fixtures::new(fixtures_dir(), SESSION)).unwrap()
+}
+
+fn year() -> ExerciseYear {
+    ExerciseYear::try_from(2015).unwrap()
+}
+
+fn day() -> ExerciseDay {
+    ExerciseDay::try_from(1).unwrap()
+}
+
+#[test]
+fn fetch_exercise_page_and_input() {
+    let server = start();
+    let client = client(&server, SESSION);
+
+    let page = client.fetch_exercise_page(year(), day()).unwrap();
+    assert!(page
+        .page_content
+        .as_str()
+        .contains("--- Day 1: Mock Sums ---"));
+    assert_eq!(
+        page.page_url.as_str(),
+        format!("{}/2015/day/1", server.url())
+    );
+    assert_eq!(
+        page.input_url.as_str(),
+        format!("{}/2015/day/1/input", server.url())
+    );
+
+    let input = client.fetch_input_page(year(), day()).unwrap();
+    assert_eq!(input.as_str(), "3 1 4 1 5\n");
+
+    assert!(matches!(
+        client.fetch_exercise_page(year(), ExerciseDay::try_from(2).unwrap()),
+        Err(Error::HttpStatus { status: 404, .. })
+    ));
+
+    let paths = server
+        .requests()
+        .into_iter()
+        .map(|r| r.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["/2015/day/1", "/2015/day/1/input", "/2015/day/2"]);
+}
+
+#[test]
+fn send_answer() {
+    let server = start();
+    let client = client(&server, SESSION);
+
+    let response = client
+        .send_answer(&Answer::from(14), year(), day(), ExercisePart::First)
+        .unwrap();
+    assert!(matches!(response, PuzzleAnswer::Success(_)));
+
+    let response = client
+        .send_answer(&Answer::from(100), year(), day(), ExercisePart::Second)
+        .unwrap();
+    assert!(matches!(response, PuzzleAnswer::TooHigh(_)));
+    assert_eq!(response.cooldown(), Some(Duration::from_secs(60)));
+
+    let request = &server.requests()[0];
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.body, "level=1&answer=14");
+}
+
+#[test]
+fn leaderboard_event_and_account() {
+    let server = start();
+    let client = client(&server, SESSION);
+
+    let leaderboard = client.fetch_private_leaderboard(year(), 1234).unwrap();
+    assert_eq!(leaderboard.members.len(), 2);
+
+    let stars = client.fetch_event_stars(year()).unwrap();
+    assert_eq!((stars.stars(1), stars.stars(2)), (2, 0));
+
+    assert_eq!(client.fetch_account().unwrap().name, ACCOUNT_NAME);
+}
+
+#[test]
+fn logged_out() {
+    let server = start();
+    let client = client(&server, "expired");
+
+    assert!(matches!(
+        client.fetch_input_page(year(), day()),
+        Err(Error::Unauthenticated)
+    ));
+    assert!(matches!(
+        client.fetch_account(),
+        Err(Error::Unauthenticated)
+    ));
+}
@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use aoc_sx_core::exercise::{Answer, ExerciseDay, ExercisePart, ExerciseYear};
+use aoc_sx_mock::{local_recordings_dir, recording_names, recordings_dir};
+use aoc_sx_webclient::{throttle::Throttle, transport::Replay, Client, Error, PuzzleAnswer};
+
+fn client() -> Client {
+    Client::new("unused".into())
+        .with_transport(Replay::new(recordings_dir()))
+        .with_throttle(Throttle::new(Duration::ZERO))
+}
+
+fn send(year: u16, day: u8, part: ExercisePart, answer: &str) -> PuzzleAnswer {
+    client()
+        .send_answer(
+            &answer.parse::<Answer>().unwrap(),
+            ExerciseYear::try_from(year).unwrap(),
+            ExerciseDay::try_from(day).unwrap(),
+            part,
+        )
+        .unwrap()
+}
+
+#[test]
+fn classify_answer_responses() {
+    use ExercisePart::{First, Second};
+
+    let success = send(2020, 1, First, "514579");
+    assert!(matches!(success, PuzzleAnswer::Success(_)));
+    assert_eq!(success.cooldown(), None);
+
+    let too_high = send(2021, 3, First, "4000000");
+    assert!(matches!(too_high, PuzzleAnswer::TooHigh(_)));
+    assert_eq!(too_high.cooldown(), Some(Duration::from_secs(60)));
+
+    let too_low = send(2022, 5, Second, "12");
+    assert!(matches!(too_low, PuzzleAnswer::TooLow(_)));
+    assert_eq!(too_low.cooldown(), Some(Duration::from_secs(300)));
+
+    assert!(matches!(
+        send(2023, 10, First, "6844"),
+        PuzzleAnswer::WrongLevel(_)
+    ));
+
+    assert_eq!(
+        send(2024, 7, First, "3749").cooldown(),
+        Some(Duration::from_secs(34))
+    );
+
+    let failed = send(2024, 7, Second, "ABCD");
+    assert!(matches!(failed, PuzzleAnswer::Failed(_)));
+    assert!(failed.message().ends_with("[Return to Day 7]"));
+}
+
+#[test]
+fn unexpected_responses() {
+    let year = ExerciseYear::try_from(2020).unwrap();
+    let day = ExerciseDay::try_from(2).unwrap();
+
+    assert!(matches!(
+        client().fetch_input_page(year, day),
+        Err(Error::RateLimited { .. })
+    ));
+    assert!(matches!(
+        client().send_answer(&Answer::from(1), year, day, ExercisePart::First),
+        Err(Error::ParseError(_))
+    ));
+}
+
+#[test]
+fn missing_recording() {
+    let result = client().fetch_exercise_page(
+        ExerciseYear::try_from(2020).unwrap(),
+        ExerciseDay::try_from(25).unwrap(),
+    );
+    assert!(matches!(result, Err(Error::Offline(_))));
+}
+
+#[test]
+fn local_answer_responses() {
+    let Some(dir) = local_recordings_dir() else {
+        eprintln!("skipped: no local recordings");
+        return;
+    };
+
+    let client = Client::new("unused".into())
+        .with_transport(Replay::new(&dir))
+        .with_throttle(Throttle::new(Duration::ZERO));
+    for name in recording_names(&dir).unwrap() {
+        // Answers with other characters than letters and digits cannot be told from their file name
+        let ["POST", year, "day", day, "answer", "level", level, "answer", answer] =
+            name.iter().map(String::as_str).collect::<Vec<_>>()[..]
+        else {
+            continue;
+        };
+        let part = match level {
+            "1" => ExercisePart::First,
+            _ => ExercisePart::Second,
+        };
+
+        let response = client
+            .send_answer(
+                &answer.parse::<Answer>().unwrap(),
+                ExerciseYear::try_from(year.parse::<u16>().unwrap()).unwrap(),
+                ExerciseDay::try_from(day.parse::<u8>().unwrap()).unwrap(),
+                part,
+            )
+            .unwrap();
+        assert!(
+            !matches!(response, PuzzleAnswer::Unknown(_)),
+            "{year} day {day} part {level}: unrecognized response {response:?}"
+        );
+    }
+}
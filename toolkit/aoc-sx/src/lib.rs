@@ -12,4 +12,5 @@ pub use thiserror;
 pub use tracing;
 
 pub use aoc_sx_algo as algo;
+pub use aoc_sx_core as core;
 pub use aoc_sx_ui as ui;